@@ -0,0 +1,41 @@
+use crate::error::PuzzleError;
+use crate::helpers::parse::{lines, paragraphs, parse_all, unsigned};
+use crate::solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, PuzzleError> {
+        let goblins = parse_all(paragraphs(lines(unsigned::<u32>)), input)?;
+        Ok(goblins
+            .iter()
+            .map(|goblin| goblin.iter().sum::<u32>())
+            .collect())
+    }
+
+    fn part_one(goblins_values: &Vec<u32>) -> Option<u32> {
+        goblins_values.iter().copied().max()
+    }
+
+    fn part_two(goblins_values: &Vec<u32>) -> Option<u32> {
+        let mut goblins_values = goblins_values.clone();
+
+        goblins_values.sort_unstable();
+        let result = goblins_values.into_iter().rev().take(3).sum::<u32>();
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day01);
+}
@@ -0,0 +1,134 @@
+use crate::error::PuzzleError;
+use crate::solution::Solution;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(PartialEq)]
+pub struct Sign {
+    shape: Shape,
+    wins: Shape,
+    loses: Shape,
+    value: u32,
+}
+
+fn shape_to_sign(shape: Shape) -> Sign {
+    let rock = Sign {
+        shape: Shape::Rock,
+        wins: Shape::Scissors,
+        loses: Shape::Paper,
+        value: 1,
+    };
+    let paper = Sign {
+        shape: Shape::Paper,
+        wins: Shape::Rock,
+        loses: Shape::Scissors,
+        value: 2,
+    };
+    let scissors = Sign {
+        shape: Shape::Scissors,
+        wins: Shape::Paper,
+        loses: Shape::Rock,
+        value: 3,
+    };
+
+    match shape {
+        Shape::Rock => rock,
+        Shape::Paper => paper,
+        Shape::Scissors => scissors,
+    }
+}
+
+/// Shape played for `column`, which `parse` has checked to be one of X, Y, Z.
+fn column_to_sign(column: char) -> Sign {
+    match column {
+        'X' => shape_to_sign(Shape::Rock),
+        'Y' => shape_to_sign(Shape::Paper),
+        _ => shape_to_sign(Shape::Scissors),
+    }
+}
+
+fn second_col_to_val(xyz: char) -> u32 {
+    match xyz {
+        'Y' => 3,
+        'Z' => 6,
+        _ => 0,
+    }
+}
+
+fn parse_turn(input: &str, line: &str) -> Result<(Sign, char), PuzzleError> {
+    let (op, column) = line
+        .split_once(' ')
+        .ok_or_else(|| PuzzleError::at(input, line, "two columns"))?;
+    let op_play = match op {
+        "A" => shape_to_sign(Shape::Rock),
+        "B" => shape_to_sign(Shape::Paper),
+        "C" => shape_to_sign(Shape::Scissors),
+        _ => return Err(PuzzleError::at(input, op, "one of A, B, C")),
+    };
+    match column.parse() {
+        Ok(column @ ('X' | 'Y' | 'Z')) => Ok((op_play, column)),
+        _ => Err(PuzzleError::at(input, column, "one of X, Y, Z")),
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<(Sign, char)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<(Sign, char)>, PuzzleError> {
+        input.lines().map(|line| parse_turn(input, line)).collect()
+    }
+
+    fn part_one(turns: &Vec<(Sign, char)>) -> Option<u32> {
+        fn process_turn((op_play, column): &(Sign, char)) -> u32 {
+            let my_play = column_to_sign(*column);
+
+            let outcome = match (op_play, &my_play) {
+                (op_play, my_play) if (op_play == my_play) => 3,
+                (op_play, my_play) if (op_play.shape == my_play.wins) => 6,
+                (op_play, my_play) if (op_play.wins == my_play.shape) => 0,
+                _ => 0,
+            };
+
+            my_play.value + outcome
+        }
+
+        let result = turns.iter().map(process_turn).sum::<u32>();
+
+        Some(result)
+    }
+
+    fn part_two(turns: &Vec<(Sign, char)>) -> Option<u32> {
+        fn process_turn((op_play, column): &(Sign, char)) -> u32 {
+            let my_play = match column {
+                'X' => shape_to_sign(op_play.wins),
+                'Y' => shape_to_sign(op_play.shape),
+                _ => shape_to_sign(op_play.loses),
+            };
+
+            my_play.value + second_col_to_val(*column)
+        }
+
+        let result = turns.iter().map(process_turn).sum::<u32>();
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day02);
+}
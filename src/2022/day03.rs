@@ -0,0 +1,89 @@
+use crate::error::PuzzleError;
+use crate::solution::Solution;
+
+fn find_common_char(line: &str) -> Option<u8> {
+    let (first_half, second_half) = line.split_at(line.len() / 2);
+    first_half
+        .bytes()
+        .find(|c| second_half.as_bytes().contains(c))
+}
+
+fn find_common_char_in_lines(lines: &[&str]) -> Option<u8> {
+    let (first, others) = lines.split_first()?;
+    first
+        .bytes()
+        .find(|c| others.iter().all(|line| line.as_bytes().contains(c)))
+}
+
+fn parse_rucksack<'a>(input: &str, line: &'a str) -> Result<&'a str, PuzzleError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(PuzzleError::at(
+            input,
+            &line[i..i + c.len_utf8()],
+            "an item a-z or A-Z",
+        ));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(PuzzleError::at(input, line, "an even number of items"));
+    }
+    find_common_char(line)
+        .ok_or_else(|| PuzzleError::at(input, line, "an item in both compartments"))?;
+    Ok(line)
+}
+
+fn to_value(letter: u8) -> u32 {
+    let res = if letter > 96 {
+        letter - 96
+    } else {
+        letter - 38
+    };
+
+    res as u32
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<&str>, PuzzleError> {
+        input
+            .lines()
+            .map(|line| parse_rucksack(input, line))
+            .collect()
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Option<u32> {
+        lines
+            .iter()
+            .map(|line| find_common_char(line).map(to_value))
+            .sum()
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Option<u32> {
+        lines
+            .chunks(3)
+            .map(|group| find_common_char_in_lines(group).map(to_value))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day03);
+
+    #[test]
+    fn test_to_value() {
+        assert_eq!(to_value(b'a'), 1);
+        assert_eq!(to_value(b'z'), 26);
+        assert_eq!(to_value(b'A'), 27);
+        assert_eq!(to_value(b'Z'), 52);
+    }
+}
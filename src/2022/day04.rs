@@ -0,0 +1,69 @@
+use crate::error::PuzzleError;
+use crate::helpers::parse::{lines, parse_all, unsigned};
+use crate::solution::Solution;
+use nom::{character::complete::char, sequence::separated_pair, IResult};
+
+type Sections = Vec<u32>;
+
+pub struct Pair {
+    first: Sections,
+    second: Sections,
+}
+
+impl Pair {
+    fn are_fully_overlapping(&self) -> bool {
+        (self.first[0] >= self.second[0] && self.first[1] <= self.second[1])
+            || (self.second[0] >= self.first[0] && self.second[1] <= self.first[1])
+    }
+
+    fn are_overlapping(&self) -> bool {
+        self.first[0] <= self.second[1] && self.second[0] <= self.first[1]
+    }
+}
+
+fn sections(input: &str) -> IResult<&str, Sections> {
+    let (input, (start, end)) = separated_pair(unsigned, char('-'), unsigned)(input)?;
+    Ok((input, vec![start, end]))
+}
+
+fn parse_pair(input: &str) -> IResult<&str, Pair> {
+    let (input, (first, second)) = separated_pair(sections, char(','), sections)(input)?;
+    Ok((input, Pair { first, second }))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Pair>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<Pair>, PuzzleError> {
+        parse_all(lines(parse_pair), input)
+    }
+
+    fn part_one(pairs: &Vec<Pair>) -> Option<u32> {
+        let res = pairs
+            .iter()
+            .filter(|pairs| pairs.are_fully_overlapping())
+            .count();
+
+        Some(res as u32)
+    }
+
+    fn part_two(pairs: &Vec<Pair>) -> Option<u32> {
+        let res = pairs.iter().filter(|pairs| pairs.are_overlapping()).count();
+
+        Some(res as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day04);
+}
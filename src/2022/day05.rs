@@ -1,43 +1,41 @@
+use crate::error::PuzzleError;
+use crate::solution::Solution;
 use regex::Regex;
 use std::collections::HashMap;
 
 #[derive(Debug)]
-struct Command {
+pub struct Command {
     from: u32,
     to: u32,
     nb: u32,
 }
 
-fn parse_commands(command_lines: Vec<String>) -> Vec<Command> {
+fn parse_commands(input: &str, command_lines: Vec<&str>) -> Result<Vec<Command>, PuzzleError> {
     let reg = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
     command_lines
         .iter()
-        .map(|command| -> Command {
-            // println!("text command : {:?}", &command);
+        .map(|command| -> Result<Command, PuzzleError> {
             let captures = reg
-                .captures(&command)
-                .expect("malformed command in input data");
+                .captures(command)
+                .ok_or_else(|| PuzzleError::at(input, command, "`move N from N to N`"))?;
 
             // println!("capture : {:?}", &captures);
-            return Command {
-                nb: captures
-                    .get(1)
-                    .map(|x| x.as_str().parse::<u32>().expect("ParseIntErro"))
-                    .expect("failed map nb"),
-                from: captures
-                    .get(2)
-                    .map(|x| x.as_str().parse::<u32>().expect("ParseIntErro"))
-                    .expect("failed map from"),
-                to: captures
-                    .get(3)
-                    .map(|x| x.as_str().parse::<u32>().expect("ParseIntErro"))
-                    .expect("failed map to"),
+            let number = |index: usize| {
+                let number = captures.get(index).unwrap().as_str();
+                number
+                    .parse::<u32>()
+                    .map_err(|_| PuzzleError::at(input, number, "a valid value"))
             };
+            Ok(Command {
+                nb: number(1)?,
+                from: number(2)?,
+                to: number(3)?,
+            })
         })
-        .collect::<Vec<Command>>()
+        .collect()
 }
 
-fn parse_stacks(stack_lines: Vec<String>) -> HashMap<u32, Vec<char>> {
+fn parse_stacks(stack_lines: Vec<&str>) -> HashMap<u32, Vec<char>> {
     stack_lines.iter().rev().fold(
         HashMap::new(),
         |mut stacks, line| -> HashMap<u32, Vec<char>> {
@@ -95,7 +93,7 @@ impl Stacks {
         //     "command {:?}, stacks before move {:#?}",
         //     &command, &self.crates
         // );
-        let final_len = &self
+        let final_len = self
             .crates
             .get(&command.from)
             .expect("from not found !")
@@ -137,74 +135,76 @@ fn get_top_crates(stacks: &Stacks) -> String {
     result
 }
 
-fn split_lines(input: &str) -> (Vec<String>, Vec<String>) {
-    let mut stack_lines: Vec<String> = Vec::new();
-    let mut command_lines: Vec<String> = Vec::new();
-    input
-        .lines()
-        .map(|line| line.to_string())
-        .for_each(|line| match line {
-            line if line.contains("[") => stack_lines.push(line),
-            line if line.contains("move") => command_lines.push(line),
-            _ => (),
-        });
+fn split_lines(input: &str) -> (Vec<&str>, Vec<&str>) {
+    let mut stack_lines: Vec<&str> = Vec::new();
+    let mut command_lines: Vec<&str> = Vec::new();
+    input.lines().for_each(|line| match line {
+        line if line.contains('[') => stack_lines.push(line),
+        line if line.contains("move") => command_lines.push(line),
+        _ => (),
+    });
 
     (stack_lines, command_lines)
 }
 
-pub fn part_one(input: &str) -> Option<String> {
-    let (stack_lines, command_lines) = split_lines(input);
+pub struct Procedure {
+    crates: HashMap<u32, Vec<char>>,
+    commands: Vec<Command>,
+}
 
-    let stacks = Stacks::new(parse_stacks(stack_lines));
-    let commands = parse_commands(command_lines);
+pub struct Day05;
 
-    let final_state = commands
-        .iter()
-        .fold(stacks, |current_state: Stacks, command| -> Stacks {
-            current_state.move_item(&command)
-        });
+impl Solution for Day05 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 5;
 
-    let result = get_top_crates(&final_state);
+    type Input<'a> = Procedure;
+    type PartOne = String;
+    type PartTwo = String;
 
-    Some(result)
-}
+    fn parse(input: &str) -> Result<Procedure, PuzzleError> {
+        let (stack_lines, command_lines) = split_lines(input);
 
-pub fn part_two(input: &str) -> Option<String> {
-    let (stack_lines, command_lines) = split_lines(input);
+        Ok(Procedure {
+            crates: parse_stacks(stack_lines),
+            commands: parse_commands(input, command_lines)?,
+        })
+    }
 
-    let stacks = Stacks::new(parse_stacks(stack_lines));
-    let commands = parse_commands(command_lines);
+    fn part_one(procedure: &Procedure) -> Option<String> {
+        let stacks = Stacks::new(procedure.crates.clone());
 
-    let final_state = commands
-        .iter()
-        .fold(stacks, |current_state: Stacks, command| -> Stacks {
-            current_state.move_items(&command)
-        });
+        let final_state = procedure
+            .commands
+            .iter()
+            .fold(stacks, |current_state: Stacks, command| -> Stacks {
+                current_state.move_item(command)
+            });
 
-    let result = get_top_crates(&final_state);
+        let result = get_top_crates(&final_state);
 
-    Some(result)
-}
+        Some(result)
+    }
+
+    fn part_two(procedure: &Procedure) -> Option<String> {
+        let stacks = Stacks::new(procedure.crates.clone());
+
+        let final_state = procedure
+            .commands
+            .iter()
+            .fold(stacks, |current_state: Stacks, command| -> Stacks {
+                current_state.move_items(command)
+            });
+
+        let result = get_top_crates(&final_state);
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+        Some(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some("CMZ".to_string()));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some("MCD".to_string()));
-    }
+    crate::example_tests!(Day05);
 }
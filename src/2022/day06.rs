@@ -1,3 +1,5 @@
+use crate::error::PuzzleError;
+use crate::solution::Solution;
 use std::collections::HashSet;
 
 #[allow(dead_code)]
@@ -5,7 +7,7 @@ fn index_of_window_without_duplicate_imperative(input: &str, window_size: u32) -
     for index in 0..(input.len() - window_size as usize) {
         let mut seen = HashSet::new();
 
-        for character in (&input[index..index + window_size as usize]).as_bytes() {
+        for character in &input.as_bytes()[index..index + window_size as usize] {
             if !seen.insert(character) {
                 break;
             }
@@ -55,7 +57,7 @@ fn index_of_window_without_duplicate_bitwise_mask(input: &str, window_size: usiz
         for index_in_window in (0..window_size).rev() {
             // assuming input is only lowercase char, subtracting by 'a' which the number 97, gives a result between 0 an 25
             // 1 << 'b' - 97 -> 1 << 98 - 97 -> 1 << 1 -> 0b00000000_00000000_00000000_00000010 -> visually shift of one position to the left (<<)
-            let mask = 1 << data[window_start_index + index_in_window] - b'a';
+            let mask = 1 << (data[window_start_index + index_in_window] - b'a');
 
             // seen 0b00000000_00000000_00000000_01010010 AND mask 0b00000000_00000000_00000000_00000010 -> 0b00000000_00000000_00000000_00000010
             // bitwise AND with a mask equals to mask if the shifted 1 is also present in the seen variable
@@ -75,36 +77,35 @@ fn index_of_window_without_duplicate_bitwise_mask(input: &str, window_size: usiz
     Some(window_size as u32 + window_start_index as u32)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    // index_of_window_without_duplicate_imperative(input, 4)
-    index_of_window_without_duplicate_functionnal(input, 4)
-}
+pub struct Day06;
 
-pub fn part_two(input: &str) -> Option<u32> {
-    // index_of_window_without_duplicate_imperative(input, 14)
-    // index_of_window_without_duplicate_functionnal(input, 14)
-    index_of_window_without_duplicate_bitwise_mask(input, 14)
-}
+impl Solution for Day06 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 6;
+
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    fn parse(input: &str) -> Result<&str, PuzzleError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        // index_of_window_without_duplicate_imperative(input, 4)
+        index_of_window_without_duplicate_functionnal(input, 4)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        // index_of_window_without_duplicate_imperative(input, 14)
+        // index_of_window_without_duplicate_functionnal(input, 14)
+        index_of_window_without_duplicate_bitwise_mask(input, 14)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_one(&input), Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_two(&input), Some(19));
-    }
+    crate::example_tests!(Day06);
 }
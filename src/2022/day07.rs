@@ -0,0 +1,186 @@
+use crate::error::PuzzleError;
+use crate::helpers::parse::{lines, parse_all, unsigned};
+use crate::solution::Solution;
+use std::collections::BTreeMap;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while},
+    character::complete::{alpha1, newline},
+    sequence::separated_pair,
+    *,
+};
+
+#[derive(Debug)]
+enum Operation<'a> {
+    Cd(Cd<'a>),
+    Ls(Vec<Files>),
+}
+
+#[derive(Debug)]
+enum Cd<'a> {
+    Root,
+    Up,
+    Down(&'a str),
+}
+
+#[derive(Debug)]
+enum Files {
+    File(u32),
+    Dir,
+}
+
+// 8504156 c.dat
+// dir d
+fn file(input: &str) -> IResult<&str, Files> {
+    // dbg!("file i", input);
+
+    let (input, (size, _name)) =
+        separated_pair(unsigned, tag(" "), take_while(|c| c != '\n'))(input)?;
+
+    // dbg!("file o", input);
+
+    Ok((input, Files::File(size)))
+}
+
+fn directory(input: &str) -> IResult<&str, Files> {
+    // dbg!("directory i", input);
+
+    let (input, _) = tag("dir ")(input)?;
+    let (input, _name) = alpha1(input)?;
+
+    // dbg!("directory o", input);
+
+    Ok((input, Files::Dir))
+}
+
+fn ls(input: &str) -> IResult<&str, Operation<'_>> {
+    // dbg!("ls", input);
+    let (input, _) = tag("$ ls")(input)?;
+    let (input, _) = newline(input)?;
+    let (input, files) = lines(alt((file, directory)))(input)?;
+
+    // dbg!(&files);
+
+    Ok((input, Operation::Ls(files)))
+}
+
+fn cd(input: &str) -> IResult<&str, Operation<'_>> {
+    // dbg!("cd", input);
+    let (input, _) = tag("$ cd ")(input)?;
+    let (input, dir) = alt((tag(".."), alpha1, tag("/")))(input)?;
+    let op = match dir {
+        "/" => Operation::Cd(Cd::Root),
+        ".." => Operation::Cd(Cd::Up),
+        name => Operation::Cd(Cd::Down(name)),
+    };
+
+    // dbg!(&op);
+
+    Ok((input, op))
+}
+
+fn commands(input: &str) -> IResult<&str, Vec<Operation<'_>>> {
+    lines(alt((ls, cd)))(input)
+}
+
+/// Sizes of the files directly in each directory, by path. The root is `""`.
+fn build_directories(cmds: Vec<Operation<'_>>) -> BTreeMap<String, Vec<u32>> {
+    let mut directories: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+    let mut context: Vec<&str> = vec![];
+    for command in cmds.iter() {
+        match command {
+            Operation::Cd(Cd::Root) => {
+                context.push("");
+            }
+            Operation::Cd(Cd::Up) => {
+                context.pop();
+            }
+            Operation::Cd(Cd::Down(name)) => {
+                context.push(name);
+            }
+            Operation::Ls(files) => {
+                directories.entry(context.join("/")).or_default();
+                for file in files.iter() {
+                    match file {
+                        Files::File(size) => {
+                            directories
+                                .entry(context.join("/"))
+                                .and_modify(|directory| directory.push(*size));
+                        }
+                        Files::Dir => (),
+                    }
+                    // dbg!("file", file, &context);
+                }
+            }
+        }
+    }
+    directories
+}
+
+fn get_directories_sizes(directories: &BTreeMap<String, Vec<u32>>) -> BTreeMap<String, u32> {
+    let mut folder_sizes: BTreeMap<String, u32> = BTreeMap::new();
+    for path in directories.keys() {
+        let dir_size = directories.iter().fold(0, |sum, (key, files)| {
+            if key.starts_with(path) {
+                sum + files.iter().sum::<u32>()
+            } else {
+                sum
+            }
+        });
+
+        folder_sizes.insert(path.to_string(), dir_size);
+    }
+
+    folder_sizes
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 7;
+
+    type Input<'a> = BTreeMap<String, u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<BTreeMap<String, u32>, PuzzleError> {
+        let cmds = parse_all(commands, input)?;
+
+        let directories = build_directories(cmds);
+        // dbg!(&directories);
+
+        Ok(get_directories_sizes(&directories))
+    }
+
+    fn part_one(folder_sizes: &BTreeMap<String, u32>) -> Option<u32> {
+        let result = folder_sizes
+            .iter()
+            .filter(|(_, &size)| size <= 100000)
+            .fold(0, |sum, (_, size)| sum + size);
+
+        // dbg!(folder_sizes, &duplicated_folder_sizes);
+        Some(result)
+    }
+
+    fn part_two(folder_sizes: &BTreeMap<String, u32>) -> Option<u32> {
+        // dbg!(&folder_sizes);
+
+        let used_space = folder_sizes.get("")?;
+        let free_space = 70000000u32.checked_sub(*used_space)?;
+
+        folder_sizes
+            .iter()
+            .filter(|(_name, &size)| size + free_space >= 30000000)
+            .map(|(_, size)| *size)
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day07);
+}
@@ -0,0 +1,82 @@
+use crate::error::PuzzleError;
+use crate::helpers::direction::Direction;
+use crate::helpers::grid::{Grid, Pos};
+use crate::helpers::parse::{grid, parse_all};
+use crate::solution::Solution;
+
+fn is_visible(grid: &Grid<u8>, pos: Pos) -> bool {
+    let height = grid[pos];
+    Direction::FOUR.iter().any(|direction| {
+        grid.ray(pos, direction.delta())
+            .all(|(_, &tree)| tree < height)
+    })
+}
+
+fn count_visible_trees(grid: &Grid<u8>) -> usize {
+    grid.positions()
+        .filter(|&pos| is_visible(grid, pos))
+        .count()
+}
+
+/// Trees seen from `pos` looking in `direction`, up to and including the first one that blocks the view.
+fn viewing_distance(grid: &Grid<u8>, pos: Pos, direction: Direction) -> usize {
+    let height = grid[pos];
+    let mut trees = 0;
+    for (_, &tree) in grid.ray(pos, direction.delta()) {
+        trees += 1;
+        if tree >= height {
+            break;
+        }
+    }
+    trees
+}
+
+fn find_highest_scenic_score(grid: &Grid<u8>) -> usize {
+    grid.positions()
+        .map(|pos| {
+            Direction::FOUR
+                .iter()
+                .map(|&direction| viewing_distance(grid, pos, direction))
+                .product()
+        })
+        .max()
+        .unwrap()
+}
+
+fn parse_grid(input: &str) -> Result<Grid<u8>, PuzzleError> {
+    parse_all(grid(|c| c.to_digit(10).map(|digit| digit as u8)), input)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Grid<u8>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Grid<u8>, PuzzleError> {
+        parse_grid(input)
+    }
+
+    fn part_one(grid: &Grid<u8>) -> Option<u32> {
+        let result = count_visible_trees(grid);
+
+        Some(result as u32)
+    }
+
+    fn part_two(grid: &Grid<u8>) -> Option<u32> {
+        let result = find_highest_scenic_score(grid);
+
+        Some(result as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day08);
+}
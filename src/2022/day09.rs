@@ -0,0 +1,86 @@
+use crate::error::PuzzleError;
+use crate::helpers::direction::Direction;
+use crate::helpers::point::Point2;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Command {
+    direction: Direction,
+    length: u32,
+}
+
+fn parse_commands(input: &str) -> Result<Vec<Command>, PuzzleError> {
+    input
+        .lines()
+        .map(|line| {
+            let (letter, length) = line
+                .split_once(' ')
+                .ok_or_else(|| PuzzleError::at(input, line, "a direction and a length"))?;
+            Ok(Command {
+                direction: letter
+                    .parse()
+                    .map_err(|_| PuzzleError::at(input, letter, "one of U, R, D, L"))?,
+                length: length
+                    .parse::<u32>()
+                    .map_err(|_| PuzzleError::at(input, length, "a number"))?,
+            })
+        })
+        .collect()
+}
+
+/// Moves every knot towards the one before it once they stop touching.
+fn pull(rope: &mut [Point2]) {
+    for tail_idx in 1..rope.len() {
+        let head = rope[tail_idx - 1];
+        if rope[tail_idx].chebyshev(head) > 1 {
+            rope[tail_idx] = rope[tail_idx].step_towards(head);
+        }
+    }
+}
+
+/// Counts the positions visited by the last knot of a rope of `rope_size` knots.
+fn count_tail_positions(commands: &[Command], rope_size: usize) -> usize {
+    let mut rope = vec![Point2::ORIGIN; rope_size];
+    let mut seen: HashSet<Point2> = HashSet::from([Point2::ORIGIN]);
+
+    for command in commands.iter() {
+        for _ in 0..command.length {
+            rope[0] += command.direction.offset();
+            pull(&mut rope);
+            seen.insert(rope[rope_size - 1]);
+        }
+    }
+
+    seen.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Command>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<Command>, PuzzleError> {
+        parse_commands(input)
+    }
+
+    fn part_one(commands: &Vec<Command>) -> Option<u32> {
+        Some(count_tail_positions(commands, 2) as u32)
+    }
+
+    fn part_two(commands: &Vec<Command>) -> Option<u32> {
+        Some(count_tail_positions(commands, 10) as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day09);
+}
@@ -0,0 +1,108 @@
+use crate::error::PuzzleError;
+use crate::helpers::parse::{lines, parse_all, signed};
+use crate::solution::Solution;
+use nom::{branch::alt, bytes::complete::tag, *};
+
+#[derive(Debug)]
+pub enum Command {
+    Noop,
+    Add(i64),
+}
+
+fn add(input: &str) -> IResult<&str, Command> {
+    let (input, _) = tag("addx ")(input)?;
+    let (input, number) = signed(input)?;
+    Ok((input, Command::Add(number)))
+}
+fn noop(input: &str) -> IResult<&str, Command> {
+    let (input, _) = tag("noop")(input)?;
+
+    Ok((input, Command::Noop))
+}
+fn commands(input: &str) -> IResult<&str, Vec<Command>> {
+    lines(alt((noop, add)))(input)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Command>;
+    type PartOne = i64;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<Command>, PuzzleError> {
+        parse_all(commands, input)
+    }
+
+    fn part_one(cmds: &Vec<Command>) -> Option<i64> {
+        let mut x = 1;
+        let mut cycles = 1;
+        let mut total = 0;
+
+        for cmd in cmds.iter() {
+            if cycles % 40 == 20 {
+                total += cycles * x;
+            }
+            cycles += 1;
+
+            if let Command::Add(num) = cmd {
+                if cycles % 40 == 20 {
+                    total += cycles * x;
+                }
+                x += num;
+                cycles += 1;
+            }
+        }
+
+        Some(total)
+    }
+
+    fn part_two(cmds: &Vec<Command>) -> Option<u32> {
+        let screen_size = 40;
+        let mut cycles = 1;
+        let mut sprite_position = 1;
+
+        for cmd in cmds.iter() {
+            let draw_pos = (cycles - 1) % screen_size;
+            if draw_pos == 0 {
+                crate::debug_println!();
+            }
+            let sprite_span = (sprite_position - 1)..=(sprite_position + 1);
+            if sprite_span.contains(&draw_pos) {
+                crate::debug_print!("#");
+            } else {
+                crate::debug_print!(".");
+            }
+
+            cycles += 1;
+
+            if let Command::Add(num) = cmd {
+                let draw_pos = (cycles - 1) % screen_size;
+                if draw_pos == 0 {
+                    crate::debug_println!();
+                }
+                // let sprite_span = (sprite_position - 1)..(sprite_position + 1);
+                if sprite_span.contains(&draw_pos) {
+                    crate::debug_print!("#");
+                } else {
+                    crate::debug_print!(".");
+                }
+
+                sprite_position += num;
+                cycles += 1;
+            }
+        }
+        crate::debug_println!();
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day10);
+}
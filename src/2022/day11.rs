@@ -0,0 +1,249 @@
+use crate::error::PuzzleError;
+use crate::solution::Solution;
+use std::{cmp::Reverse, collections::VecDeque};
+
+use crate::helpers::parse::{field, list, paragraphs, parse_all, unsigned};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace1, newline},
+    sequence::{delimited, preceded, tuple},
+    IResult, *,
+};
+
+type MonkeyIndex = u32;
+type ItemWorryLevel = u128;
+
+#[derive(Clone, Debug)]
+struct Test {
+    divisible: ItemWorryLevel,
+    true_recipient: MonkeyIndex,
+    false_recipient: MonkeyIndex,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    #[allow(dead_code)]
+    index: MonkeyIndex,
+    items: VecDeque<ItemWorryLevel>,
+    operation: Operation,
+    throw_divisor: Test,
+    inspections: u128,
+}
+
+#[derive(Clone, Debug)]
+enum Value {
+    Old,
+    Num(ItemWorryLevel),
+}
+impl Value {
+    fn number(&self, old: ItemWorryLevel) -> ItemWorryLevel {
+        match self {
+            Value::Num(n) => *n,
+            Value::Old => old,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Add((Value, Value), String),
+    Multiply((Value, Value), String),
+}
+impl Operation {
+    fn apply(&self, old: ItemWorryLevel) -> ItemWorryLevel {
+        let (_s, _b, result) = match self {
+            Operation::Add((a, b), s) => (s, b, a.number(old) + b.number(old)),
+            Operation::Multiply((a, b), s) => (s, b, a.number(old) * b.number(old)),
+        };
+        // println!("{} {} to {}", s, b.number(old), result);
+        result
+    }
+}
+
+impl Monkey {
+    fn inspect_item(&mut self) -> Option<ItemWorryLevel> {
+        if self.items.is_empty() {
+            // println!("Monkey {} has no item to inspect", self.index);
+            return None;
+        }
+        let item = self.items.pop_front().expect("pop_front failed");
+        // println!(
+        //     "Monkey {} inspects an item with a worry level of === {} ===",
+        //     self.index, item
+        // );
+        self.inspections += 1;
+
+        let new = self.operation.apply(item);
+
+        Some(new)
+    }
+
+    fn decide_throw(&self, item: &ItemWorryLevel) -> MonkeyIndex {
+        if item.is_multiple_of(self.throw_divisor.divisible) {
+            self.throw_divisor.true_recipient
+        } else {
+            self.throw_divisor.false_recipient
+        }
+    }
+}
+
+fn value(input: &str) -> IResult<&str, Value> {
+    alt((tag("old").map(|_| Value::Old), unsigned.map(Value::Num)))(input)
+}
+
+fn parse_operation(input: &str) -> IResult<&str, Operation> {
+    let (input, (a, raw_operation, b)) = tuple((
+        value,
+        delimited(multispace1, alt((tag("*"), tag("+"))), multispace1),
+        value,
+    ))(input)?;
+
+    let operation = match raw_operation {
+        "*" => Operation::Multiply((a, b), String::from("  Worry level is multiplied by")),
+        "+" => Operation::Add((a, b), String::from("  Worry level is increased by")),
+        _ => panic!("Invalid input"),
+    };
+
+    Ok((input, operation))
+}
+
+fn parse_test(input: &str) -> IResult<&str, Test> {
+    let (input, divisor) = preceded(newline, field("Test: divisible by", unsigned))(input)?;
+    let (input, true_recipient) =
+        preceded(newline, field("If true: throw to monkey", unsigned))(input)?;
+    let (input, false_recipient) =
+        preceded(newline, field("If false: throw to monkey", unsigned))(input)?;
+
+    Ok((
+        input,
+        Test {
+            divisible: divisor,
+            true_recipient,
+            false_recipient,
+        },
+    ))
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, index) = delimited(tag("Monkey "), unsigned, tag(":"))(input)?;
+    let (input, items) = preceded(newline, field("Starting items:", list(unsigned)))(input)?;
+    let (input, operation) = preceded(newline, field("Operation: new =", parse_operation))(input)?;
+
+    let (input, test) = parse_test(input)?;
+
+    let items: VecDeque<ItemWorryLevel> = items.into_iter().collect();
+
+    // dbg!(input, index);
+    let monkey = Monkey {
+        index,
+        items,
+        operation,
+        throw_divisor: test,
+        inspections: 0,
+    };
+
+    Ok((input, monkey))
+}
+
+fn parse_data(input: &str) -> IResult<&str, Vec<Monkey>> {
+    paragraphs(parse_monkey)(input)
+}
+
+fn get_score(monkeys: Vec<Monkey>) -> u128 {
+    let mut vec = monkeys.clone();
+
+    vec.sort_unstable_by_key(|monkey| Reverse(monkey.inspections));
+
+    vec[0].inspections * vec[1].inspections
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Vec<Monkey>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, PuzzleError> {
+        parse_all(parse_data, input)
+    }
+
+    fn part_one(monkeys: &Vec<Monkey>) -> Option<u128> {
+        let number_turns = 20;
+
+        let mut monkeys = monkeys.clone();
+
+        crate::debug_println!("{:#?}", monkeys);
+
+        for turn in 0..number_turns {
+            crate::debug_println!("========== Turn {} ==========", turn);
+            for monkey_index in 0..monkeys.len() {
+                while let Some(item) = monkeys[monkey_index].inspect_item() {
+                    let new = item / 3;
+                    // println!(
+                    //     "  Monkey gets bored with item. Worry level is divided by 3 to {}.",
+                    //     new
+                    // );
+                    let receiver_index = monkeys[monkey_index].decide_throw(&new);
+                    monkeys[receiver_index as usize].items.push_back(new);
+                    // println!(
+                    //     "  Item with worry level {} is thrown to monkey {}",
+                    //     item, receiver_index
+                    // );
+                }
+            }
+        }
+
+        crate::debug_println!("{:#?}", monkeys);
+
+        Some(get_score(monkeys))
+    }
+
+    fn part_two(monkeys: &Vec<Monkey>) -> Option<u128> {
+        let number_turns = 10000;
+
+        let mut monkeys = monkeys.clone();
+
+        let common_multiple: u128 = monkeys
+            .iter()
+            .map(|monkey| monkey.throw_divisor.divisible)
+            .product::<u128>();
+
+        // dbg!("+============", common_multiple);
+
+        for turn in 0..number_turns {
+            crate::debug_println!("========== Turn {} ==========", turn);
+            for monkey_index in 0..monkeys.len() {
+                while let Some(item) = monkeys[monkey_index].inspect_item() {
+                    // let new = item / 3;
+                    // println!(
+                    //     "  Monkey gets bored with item. Worry level is divided by 3 to {}.",
+                    //     new
+                    // );
+                    let new = item % common_multiple;
+                    let receiver_index = monkeys[monkey_index].decide_throw(&new);
+                    monkeys[receiver_index as usize].items.push_back(new);
+                    // println!(
+                    //     "  Item with worry level {} is thrown to monkey {}",
+                    //     item, receiver_index
+                    // );
+                }
+            }
+        }
+
+        crate::debug_println!("{:#?}", monkeys);
+
+        Some(get_score(monkeys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day11);
+}
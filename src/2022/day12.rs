@@ -0,0 +1,135 @@
+use crate::error::PuzzleError;
+use crate::helpers::direction::Direction;
+use crate::helpers::grid::Grid;
+use crate::helpers::parse::{grid, parse_all};
+use crate::helpers::point::Point2;
+use crate::helpers::search;
+use crate::solution::Solution;
+use colored::Colorize;
+use std::fmt::Display;
+
+#[derive(Clone)]
+pub struct Heightmap {
+    heights: Grid<u8>,
+    start: Point2,
+    goal: Point2,
+}
+
+fn convert_to_char(num: u8) -> char {
+    let base = b'a';
+    let result = base + num;
+    result as char
+}
+
+impl Display for Heightmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (pos, &height) in self.heights.iter() {
+            let pos = Point2::from(pos);
+            if pos.x == 0 {
+                writeln!(f)?;
+            }
+
+            if pos == self.goal {
+                write!(f, "{}", "E".green())?;
+            } else if pos == self.start {
+                write!(f, "{}", "S".red())?;
+            } else {
+                write!(f, "{}", convert_to_char(height).to_string().blue())?;
+            }
+        }
+
+        writeln!(f)
+    }
+}
+
+fn is_climbable(test: u8, curr: u8) -> bool {
+    (test as i16 - curr as i16) <= 1
+}
+
+impl Heightmap {
+    fn height(&self, pos: &Point2) -> u8 {
+        *self.heights.get_point(*pos).expect("outside of the grid")
+    }
+
+    fn successors(&self, current: &Point2) -> Vec<Point2> {
+        let height = self.height(current);
+
+        Direction::FOUR
+            .iter()
+            .map(|direction| *current + direction.offset())
+            .filter(|&pos| {
+                self.heights
+                    .get_point(pos)
+                    .is_some_and(|&next| is_climbable(next, height))
+            })
+            .collect()
+    }
+}
+
+fn parse_grid(input: &str) -> Result<Heightmap, PuzzleError> {
+    let cells = parse_all(
+        grid(|c| (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)),
+        input,
+    )?;
+    let start = cells
+        .position(|&c| c == 'S')
+        .ok_or_else(|| PuzzleError::at_end(input, "a start `S`"))?;
+    let goal = cells
+        .position(|&c| c == 'E')
+        .ok_or_else(|| PuzzleError::at_end(input, "a goal `E`"))?;
+
+    Ok(Heightmap {
+        heights: cells.map(|&c| match c {
+            'S' => 0,
+            'E' => 25,
+            c => c as u8 - b'a',
+        }),
+        start: Point2::from(start),
+        goal: Point2::from(goal),
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Heightmap;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Heightmap, PuzzleError> {
+        parse_grid(input)
+    }
+
+    fn part_one(map: &Heightmap) -> Option<u32> {
+        let end = map.goal;
+        let search = search::astar(
+            map.start,
+            |p| map.successors(p).into_iter().map(|next| (next, 1)),
+            |p| p.manhattan(end),
+            |&p| p == end,
+        );
+        search.cost().map(|steps| steps as u32)
+    }
+
+    fn part_two(map: &Heightmap) -> Option<u32> {
+        // walk down from the goal once instead of climbing from every `a`.
+        let cells = map.heights.positions().map(Point2::from);
+        let predecessors = search::reverse_graph(cells, |p| map.successors(p));
+        let search = search::bfs(
+            map.goal,
+            |p| predecessors.get(p).cloned().unwrap_or_default(),
+            |p| map.height(p) == 0,
+        );
+        search.cost().map(|steps| steps as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day12);
+}
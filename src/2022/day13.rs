@@ -1,13 +1,9 @@
+use crate::error::PuzzleError;
+use crate::helpers::parse::{paragraphs, parse_all, unsigned};
+use crate::solution::Solution;
 use std::cmp::Ordering;
 
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::digit1,
-    multi::{separated_list0, separated_list1},
-    sequence::tuple,
-    IResult,
-};
+use nom::{branch::alt, bytes::complete::tag, multi::separated_list0, sequence::tuple, IResult};
 
 // #[derive(PartialEq, Debug)]
 // enum Comparison {
@@ -25,13 +21,13 @@ use nom::{
 // }
 
 #[derive(Debug, PartialEq, Clone)]
-enum Cell {
+pub enum Cell {
     Val(u32),
     Cells(Vec<Cell>),
 }
 
 impl Cell {
-    fn compare(self: &Self, b: &Cell) -> Ordering {
+    fn compare(&self, b: &Cell) -> Ordering {
         match (self, b) {
             (Cell::Val(v_a), Cell::Val(v_b)) if v_a < v_b => Ordering::Less,
             (Cell::Val(v_a), Cell::Val(v_b)) if v_a == v_b => Ordering::Equal,
@@ -67,9 +63,9 @@ impl Cell {
 }
 
 fn parse_plain_cell(input: &str) -> IResult<&str, Cell> {
-    let (input, plain) = digit1(input)?;
+    let (input, plain) = unsigned(input)?;
 
-    Ok((input, Cell::Val(plain.parse::<u32>().unwrap())))
+    Ok((input, Cell::Val(plain)))
 }
 
 fn parse_array_cell(input: &str) -> IResult<&str, Cell> {
@@ -94,72 +90,67 @@ fn parse_pairs(input: &str) -> IResult<&str, (Cell, Cell)> {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<(Cell, Cell)>> {
-    separated_list1(tag("\n\n"), parse_pairs)(input)
+    paragraphs(parse_pairs)(input)
 }
 
-fn parse_input_2(input: &str) -> IResult<&str, Vec<Cell>> {
-    separated_list1(alt((tag("\n\n"), tag("\n"))), parse_item)(input)
-}
+pub struct Day13;
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let (_, pairs) = parse_input(input).expect("parsing error");
+impl Solution for Day13 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 13;
 
-    Some(
-        pairs
-            .iter()
-            .enumerate()
-            .filter_map(|(index, (pair_a, pair_b))| {
-                if let Ordering::Less = pair_a.compare(&pair_b) {
-                    Some(index + 1)
-                } else {
-                    None
-                }
-            })
-            .sum(),
-    )
-}
+    type Input<'a> = Vec<(Cell, Cell)>;
+    type PartOne = usize;
+    type PartTwo = usize;
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let input = format!("[[2]]\n[[6]]\n{}", input);
-    let (_, mut items) = parse_input_2(&input[..]).expect("parsing error");
+    fn parse(input: &str) -> Result<Vec<(Cell, Cell)>, PuzzleError> {
+        parse_all(parse_input, input)
+    }
 
-    items.sort_by(|a, b| a.compare(&b));
+    fn part_one(pairs: &Vec<(Cell, Cell)>) -> Option<usize> {
+        Some(
+            pairs
+                .iter()
+                .enumerate()
+                .filter_map(|(index, (pair_a, pair_b))| {
+                    if let Ordering::Less = pair_a.compare(pair_b) {
+                        Some(index + 1)
+                    } else {
+                        None
+                    }
+                })
+                .sum(),
+        )
+    }
 
-    let pos = items
-        .iter()
-        .clone()
-        .enumerate()
-        .filter(|&(_, item)| {
-            item == &Cell::Cells(vec![Cell::Cells(vec![Cell::Val(2)])])
-                || item == &Cell::Cells(vec![Cell::Cells(vec![Cell::Val(6)])])
-        })
-        .map(|(index, _)| index + 1)
-        .product::<usize>();
+    fn part_two(pairs: &Vec<(Cell, Cell)>) -> Option<usize> {
+        let dividers = [
+            Cell::Cells(vec![Cell::Cells(vec![Cell::Val(2)])]),
+            Cell::Cells(vec![Cell::Cells(vec![Cell::Val(6)])]),
+        ];
+        let mut items: Vec<&Cell> = dividers
+            .iter()
+            .chain(pairs.iter().flat_map(|(a, b)| [a, b]))
+            .collect();
 
-    Some(pos)
-}
+        items.sort_by(|a, b| a.compare(b));
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+        let pos = items
+            .iter()
+            .enumerate()
+            .filter(|&(_, item)| dividers.contains(item))
+            .map(|(index, _)| index + 1)
+            .product::<usize>();
+
+        Some(pos)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), Some(140));
-    }
+    crate::example_tests!(Day13);
 
     #[test]
     fn test_compare_cells() {
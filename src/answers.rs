@@ -0,0 +1,191 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::year::get_day_path;
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+/// Known-correct answers for the real input of a day, stored in `src/<year>/answers/NN.toml`:
+///
+/// ```toml
+/// part1 = 24000
+/// part2 = "MCD"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(path, e) => {
+                write!(f, "could not read answers \"{}\": {}", path.display(), e)
+            }
+            AnswersError::Parse(path, e) => {
+                write!(f, "could not parse answers \"{}\": {}", path.display(), e)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    Missing,
+}
+
+pub fn get_answers_path(year: u16, day: u8) -> PathBuf {
+    get_day_path(year, "answers", day, "toml")
+}
+
+pub fn parse_answers(content: &str) -> Result<Answers, String> {
+    let table: toml::Table = content
+        .parse()
+        .map_err(|e: toml::de::Error| e.to_string())?;
+
+    let get = |key: &str| -> Result<Option<String>, String> {
+        match table.get(key) {
+            None => Ok(None),
+            Some(toml::Value::String(s)) => Ok(Some(s.clone())),
+            Some(toml::Value::Integer(i)) => Ok(Some(i.to_string())),
+            Some(other) => Err(format!(
+                "\"{}\" must be a string or an integer, found {}",
+                key,
+                other.type_str()
+            )),
+        }
+    };
+
+    Ok(Answers {
+        part1: get("part1")?,
+        part2: get("part2")?,
+    })
+}
+
+/// Reads the recorded answers of a day. Returns `Ok(None)` if none were recorded yet.
+pub fn read_answers(year: u16, day: u8) -> Result<Option<Answers>, AnswersError> {
+    let path = get_answers_path(year, day);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(AnswersError::Io(path, e)),
+    };
+
+    parse_answers(&content)
+        .map(Some)
+        .map_err(|e| AnswersError::Parse(path, e))
+}
+
+/// Renders answers in the format read by [`parse_answers`]. Integers are written unquoted.
+pub fn render_answers(answers: &Answers) -> String {
+    let render_value = |value: &str| match value.parse::<i64>() {
+        Ok(i) if i.to_string() == value => value.to_string(),
+        _ => toml::Value::String(value.to_string()).to_string(),
+    };
+
+    [("part1", &answers.part1), ("part2", &answers.part2)]
+        .iter()
+        .filter_map(|(key, value)| {
+            value
+                .as_ref()
+                .map(|value| format!("{} = {}\n", key, render_value(value)))
+        })
+        .collect()
+}
+
+/// Stores the answer of a part in `src/<year>/answers/NN.toml`, keeping the other part.
+pub fn record_answer(year: u16, day: u8, part: u8, answer: &str) -> Result<PathBuf, AnswersError> {
+    let mut answers = read_answers(year, day)?.unwrap_or_default();
+    match part {
+        1 => answers.part1 = Some(answer.to_string()),
+        _ => answers.part2 = Some(answer.to_string()),
+    }
+
+    let path = get_answers_path(year, day);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| AnswersError::Io(path.clone(), e))?;
+    }
+    fs::write(&path, render_answers(&answers)).map_err(|e| AnswersError::Io(path.clone(), e))?;
+    Ok(path)
+}
+
+pub fn verify(expected: Option<&str>, actual: Option<&str>) -> Verdict {
+    match (expected, actual) {
+        (None, _) => Verdict::Missing,
+        (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+        (Some(expected), actual) => Verdict::Fail {
+            expected: expected.to_string(),
+            actual: actual.map(|a| a.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("part1 = 24000\npart2 = \"MCD\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("MCD"));
+
+        let answers = parse_answers("part1 = \"2713310158\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("2713310158"));
+        assert_eq!(answers.get(2), None);
+
+        assert!(parse_answers("part1 = [1, 2]").is_err());
+        assert!(parse_answers("part1 = ").is_err());
+    }
+
+    #[test]
+    fn test_render_answers() {
+        let answers = Answers {
+            part1: Some("24000".into()),
+            part2: Some("MCD".into()),
+        };
+        assert_eq!(render_answers(&answers), "part1 = 24000\npart2 = \"MCD\"\n");
+        assert_eq!(parse_answers(&render_answers(&answers)).unwrap(), answers);
+
+        let answers = Answers {
+            part1: None,
+            part2: Some("007".into()),
+        };
+        assert_eq!(render_answers(&answers), "part2 = \"007\"\n");
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(verify(Some("7"), Some("7")), Verdict::Pass);
+        assert_eq!(verify(None, Some("7")), Verdict::Missing);
+        assert_eq!(
+            verify(Some("7"), None),
+            Verdict::Fail {
+                expected: "7".into(),
+                actual: None
+            }
+        );
+    }
+}
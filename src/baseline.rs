@@ -0,0 +1,252 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::{nanos, BenchResult};
+use crate::input::project_root;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Write},
+    fs, io,
+    path::PathBuf,
+};
+
+const BASELINE_DIR: &str = "target/aoc-baselines";
+
+#[derive(Debug)]
+pub enum BaselineError {
+    InvalidName(String),
+    /// The baseline was saved for another year.
+    Year {
+        name: String,
+        year: u16,
+        expected: u16,
+    },
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, serde_json::Error),
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineError::InvalidName(name) => write!(
+                f,
+                "invalid baseline name \"{}\": use letters, digits, '-' and '_' only.",
+                name
+            ),
+            BaselineError::Year {
+                name,
+                year,
+                expected,
+            } => write!(
+                f,
+                "baseline \"{}\" was saved for {}, not {}.",
+                name, year, expected
+            ),
+            BaselineError::Io(path, e) => {
+                write!(f, "could not access baseline \"{}\": {}", path.display(), e)
+            }
+            BaselineError::Parse(path, e) => {
+                write!(f, "could not parse baseline \"{}\": {}", path.display(), e)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    pub name: String,
+    pub year: u16,
+    pub benchmarks: Vec<BenchResult>,
+}
+
+/// Median timing of a part compared against a saved baseline.
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline_ns: u128,
+    pub current_ns: u128,
+    pub change_pct: f64,
+    pub regressed: bool,
+}
+
+pub fn get_path(name: &str) -> Result<PathBuf, BaselineError> {
+    let is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if !is_valid {
+        return Err(BaselineError::InvalidName(name.to_string()));
+    }
+
+    Ok(project_root()
+        .join(BASELINE_DIR)
+        .join(format!("{}.json", name)))
+}
+
+pub fn save(name: &str, year: u16, benchmarks: &[BenchResult]) -> Result<PathBuf, BaselineError> {
+    let path = get_path(name)?;
+    let baseline = Baseline {
+        name: name.to_string(),
+        year,
+        benchmarks: benchmarks.to_vec(),
+    };
+
+    fs::create_dir_all(project_root().join(BASELINE_DIR))
+        .map_err(|e| BaselineError::Io(path.clone(), e))?;
+    let json = serde_json::to_string_pretty(&baseline)
+        .map_err(|e| BaselineError::Parse(path.clone(), e))?;
+    fs::write(&path, json).map_err(|e| BaselineError::Io(path.clone(), e))?;
+
+    Ok(path)
+}
+
+/// Loads a baseline to compare a run of `year` against. Fails if it was saved for another year.
+pub fn load(name: &str, year: u16) -> Result<Baseline, BaselineError> {
+    let path = get_path(name)?;
+    let json = fs::read_to_string(&path).map_err(|e| BaselineError::Io(path.clone(), e))?;
+    let baseline: Baseline =
+        serde_json::from_str(&json).map_err(|e| BaselineError::Parse(path, e))?;
+    check_year(baseline, year)
+}
+
+fn check_year(baseline: Baseline, year: u16) -> Result<Baseline, BaselineError> {
+    if baseline.year == year {
+        return Ok(baseline);
+    }
+    Err(BaselineError::Year {
+        name: baseline.name,
+        year: baseline.year,
+        expected: year,
+    })
+}
+
+/// Compares medians of all parts that are solved in both runs.
+/// A part regressed if its median grew by more than `threshold_pct` percent.
+pub fn compare(
+    baseline: &Baseline,
+    current: &[BenchResult],
+    threshold_pct: f64,
+) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|result| {
+            let current_ns = result.stats?.median_ns;
+            let baseline_ns = baseline
+                .benchmarks
+                .iter()
+                .find(|b| b.day == result.day && b.part == result.part)?
+                .stats?
+                .median_ns;
+
+            let change_pct = if baseline_ns == 0 {
+                0_f64
+            } else {
+                (current_ns as f64 - baseline_ns as f64) / baseline_ns as f64 * 100_f64
+            };
+
+            Some(Comparison {
+                day: result.day,
+                part: result.part,
+                baseline_ns,
+                current_ns,
+                change_pct,
+                regressed: change_pct > threshold_pct,
+            })
+        })
+        .collect()
+}
+
+pub fn render_comparison(name: &str, comparisons: &[Comparison]) -> String {
+    let mut out = format!(
+        "Compared against baseline \"{}\":\n{:<6} {:<6} {:>12} {:>12} {:>10}\n",
+        name, "Day", "Part", "Baseline", "Current", "Change"
+    );
+    for c in comparisons {
+        let _ = writeln!(
+            out,
+            "{:<6} {:<6} {:>12} {:>12} {:>9.1}%{}",
+            format!("{:02}", c.day),
+            c.part,
+            format!("{:.2?}", nanos(c.baseline_ns)),
+            format!("{:.2?}", nanos(c.current_ns)),
+            c.change_pct,
+            if c.regressed { "  regressed" } else { "" }
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Stats;
+
+    fn bench(day: u8, part: u8, median_ns: Option<u128>) -> BenchResult {
+        BenchResult {
+            day,
+            part,
+            answer: median_ns.map(|_| "0".to_string()),
+            stats: median_ns.map(|median_ns| Stats {
+                samples: 10,
+                min_ns: median_ns,
+                median_ns,
+                mean_ns: median_ns,
+                stddev_ns: 0,
+            }),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline {
+            name: "main".into(),
+            year: 2022,
+            benchmarks: vec![
+                bench(6, 1, Some(1000)),
+                bench(6, 2, Some(1000)),
+                bench(7, 1, None),
+            ],
+        };
+        let current = vec![
+            bench(6, 1, Some(1050)),
+            bench(6, 2, Some(1200)),
+            bench(7, 1, Some(10)),
+        ];
+
+        let comparisons = compare(&baseline, &current, 10_f64);
+
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert!((comparisons[1].change_pct - 20_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_check_year() {
+        let baseline = |year| Baseline {
+            name: "main".into(),
+            year,
+            benchmarks: vec![],
+        };
+
+        assert!(check_year(baseline(2022), 2022).is_ok());
+        assert_eq!(
+            check_year(baseline(2022), 2023).unwrap_err().to_string(),
+            "baseline \"main\" was saved for 2022, not 2023."
+        );
+    }
+
+    #[test]
+    fn test_get_path() {
+        assert_eq!(
+            get_path("main").unwrap(),
+            project_root().join("target/aoc-baselines/main.json")
+        );
+        assert!(get_path("../main").is_err());
+        assert!(get_path("").is_err());
+    }
+}
@@ -0,0 +1,209 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::error::PuzzleError;
+use crate::record::Outcome;
+use crate::{ANSI_ITALIC, ANSI_RESET};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug)]
+pub struct BenchOptions {
+    /// Runs before measuring starts, to warm up caches and the allocator.
+    pub warmup: u32,
+    /// Maximum number of measured runs.
+    pub samples: u32,
+    /// Stops sampling early once this much time was spent measuring.
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            samples: 100,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub stddev_ns: u128,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<u128> = samples.iter().map(|d| d.as_nanos()).collect();
+        nanos.sort_unstable();
+
+        let count = nanos.len();
+        let median_ns = if count.is_multiple_of(2) {
+            (nanos[count / 2 - 1] + nanos[count / 2]) / 2
+        } else {
+            nanos[count / 2]
+        };
+        let mean = nanos.iter().sum::<u128>() as f64 / count as f64;
+        let variance = nanos
+            .iter()
+            .map(|&n| (n as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Some(Stats {
+            samples: count,
+            min_ns: nanos[0],
+            median_ns,
+            mean_ns: mean.round() as u128,
+            stddev_ns: variance.sqrt().round() as u128,
+        })
+    }
+}
+
+/// Benchmark outcome of a single part. `stats` is `None` for unsolved parts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub stats: Option<Stats>,
+    /// Why the input could not be parsed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BenchResult {
+    pub fn unsolved(day: u8, part: u8) -> Self {
+        BenchResult {
+            day,
+            part,
+            answer: None,
+            stats: None,
+            error: None,
+        }
+    }
+
+    /// A part whose input could not be parsed.
+    pub fn parse_error(day: u8, part: u8, error: &PuzzleError) -> Self {
+        BenchResult {
+            error: Some(error.to_string()),
+            ..BenchResult::unsolved(day, part)
+        }
+    }
+}
+
+pub fn bench_part<O: Outcome>(
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> O,
+    input: &str,
+    options: &BenchOptions,
+) -> BenchResult {
+    let answer = match func(input).into_answer() {
+        Ok(Some(answer)) => answer,
+        Ok(None) => return BenchResult::unsolved(day, part),
+        Err(e) => return BenchResult::parse_error(day, part, &e),
+    };
+
+    for _ in 0..options.warmup {
+        func(input);
+    }
+
+    let mut samples = Vec::with_capacity(options.samples as usize);
+    let started = Instant::now();
+    while samples.len() < options.samples.max(1) as usize {
+        let timer = Instant::now();
+        func(input);
+        samples.push(timer.elapsed());
+
+        if started.elapsed() >= options.budget {
+            break;
+        }
+    }
+
+    BenchResult {
+        day,
+        part,
+        answer: Some(answer),
+        stats: Stats::from_samples(&samples),
+        error: None,
+    }
+}
+
+pub fn print_bench_result(result: &BenchResult) {
+    if let Some(error) = &result.error {
+        eprintln!("Could not parse the input: {}", error);
+        return;
+    }
+
+    match (&result.answer, &result.stats) {
+        (Some(answer), Some(stats)) => {
+            println!(
+                "{} {}(median: {:.2?}, min: {:.2?}, mean: {:.2?}, σ: {:.2?}, {} samples){}",
+                answer,
+                ANSI_ITALIC,
+                nanos(stats.median_ns),
+                nanos(stats.min_ns),
+                nanos(stats.mean_ns),
+                nanos(stats.stddev_ns),
+                stats.samples,
+                ANSI_RESET
+            );
+        }
+        _ => {
+            println!("not solved.");
+        }
+    }
+}
+
+pub fn nanos(ns: u128) -> Duration {
+    Duration::from_nanos(ns as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = [4, 2, 8, 6]
+            .iter()
+            .map(|&n| Duration::from_nanos(n))
+            .collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min_ns, 2);
+        assert_eq!(stats.median_ns, 5);
+        assert_eq!(stats.mean_ns, 5);
+        // population standard deviation of [2, 4, 6, 8] is sqrt(5).
+        assert_eq!(stats.stddev_ns, 2);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_part_respects_sample_count() {
+        let options = BenchOptions {
+            warmup: 1,
+            samples: 7,
+            budget: Duration::from_secs(60),
+        };
+        let result = bench_part(1, 1, |input: &str| Some(input.len()), "abc", &options);
+
+        assert_eq!(result.answer, Some("3".to_string()));
+        assert_eq!(result.stats.unwrap().samples, 7);
+
+        let unsolved = bench_part(1, 2, |_: &str| None::<u32>, "abc", &options);
+        assert_eq!(unsolved.stats, None);
+    }
+}
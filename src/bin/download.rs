@@ -2,23 +2,32 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::cache::{self, Cache, Fetched};
+use advent_of_code::client::{get_input_path, get_puzzle_path, Client, ClientError};
+use advent_of_code::config;
 use std::process;
 
 struct Args {
     day: u8,
     year: Option<u16>,
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
+        force: args.contains("--force"),
         year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
     })
 }
 
 fn main() {
+    if let Err(e) = config::init() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
@@ -27,20 +36,65 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    let client = match Client::from_env(args.year) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(e.exit_code());
+        }
+    };
 
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
+    let mut cache = match Cache::open(&cache::get_cache_dir()) {
+        Ok(cache) => cache,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(e.exit_code());
         }
+    };
+
+    println!("Downloading day {}, {}...", args.day, client.year());
+
+    let input_path = get_input_path(client.year(), args.day);
+    let fetched = match cache::fetch_input(&client, &mut cache, &input_path, args.day, args.force) {
+        Ok(fetched) => fetched,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            process::exit(1);
+            eprintln!("Failed to download the input of day {}: {}", args.day, e);
+            process::exit(e.exit_code());
+        }
+    };
+
+    let offline = fetched == Fetched::Restored;
+    let puzzle = match client.download_puzzle(args.day) {
+        Ok(path) => Some(path),
+        // The local copy of the puzzle is good enough when offline.
+        Err(ClientError::Network { .. })
+            if offline && get_puzzle_path(client.year(), args.day).exists() =>
+        {
+            None
         }
+        Err(e) => {
+            eprintln!("Failed to download the puzzle of day {}: {}", args.day, e);
+            process::exit(e.exit_code());
+        }
+    };
+
+    println!("---");
+    match fetched {
+        Fetched::Existing => println!(
+            "🎄 Kept existing input \"{}\", use `--force` to download it again.",
+            input_path.display()
+        ),
+        Fetched::Downloaded => println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            input_path.display()
+        ),
+        Fetched::Restored => println!(
+            "🎄 Offline, restored input \"{}\" from the cache.",
+            input_path.display()
+        ),
+    }
+    match puzzle {
+        Some(path) => println!("🎄 Successfully wrote puzzle to \"{}\".", path.display()),
+        None => println!("🎄 Offline, kept the existing puzzle."),
     }
 }
@@ -0,0 +1,141 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::get_puzzle_path;
+use advent_of_code::examples::{self, get_example_path, get_expectations_path};
+use advent_of_code::{config, extract, year};
+use std::{fs, path::Path, process};
+
+struct Args {
+    year: u16,
+    day: u8,
+    dry_run: bool,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        dry_run: args.contains(["-n", "--dry-run"]),
+        force: args.contains("--force"),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(year::default_year),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Writes `content` to `path`, or only prints it when doing a dry run.
+fn write(path: &Path, content: &str, dry_run: bool) {
+    if dry_run {
+        println!("Would write \"{}\":", path.display());
+        for line in content.lines() {
+            println!("  | {}", line);
+        }
+        return;
+    }
+
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    match fs::write(path, content) {
+        Ok(_) => println!("Wrote \"{}\"", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    if let Err(e) = config::init() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo extract 7 --dry-run`");
+            process::exit(1);
+        }
+    };
+
+    let puzzle_path = get_puzzle_path(args.year, args.day);
+    let markdown = match fs::read_to_string(&puzzle_path) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            eprintln!(
+                "Could not read puzzle \"{}\": {}. Run `cargo download {}` first.",
+                puzzle_path.display(),
+                e,
+                args.day
+            );
+            process::exit(1);
+        }
+    };
+
+    let extracted = extract::extract(&markdown);
+    if extracted.is_empty() {
+        eprintln!(
+            "Found no example in \"{}\", please copy it by hand.",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    }
+
+    let expectations_path = get_expectations_path(args.year, args.day);
+    let current_expectations = fs::read_to_string(&expectations_path).unwrap_or_default();
+    let mut expectations = match examples::parse_expectations(&current_expectations) {
+        Ok(expectations) => expectations,
+        Err(e) => {
+            eprintln!("Could not parse \"{}\": {}", expectations_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    for example in extracted {
+        let path = get_example_path(args.year, args.day, &example.name);
+        let current = fs::read_to_string(&path).unwrap_or_default();
+
+        // older example files were written without a trailing newline.
+        let unchanged = current.trim_end_matches('\n') == example.input.trim_end_matches('\n');
+        if current.trim().is_empty() || args.force {
+            if current != example.input {
+                write(&path, &example.input, args.dry_run);
+            }
+        } else if !unchanged {
+            println!(
+                "Kept \"{}\", it differs from the puzzle. Use `--force` to overwrite.",
+                path.display()
+            );
+        }
+
+        let expected = expectations.entry(example.name).or_default();
+        for (slot, proposed) in [
+            (&mut expected.part1, example.expected.part1),
+            (&mut expected.part2, example.expected.part2),
+        ] {
+            if proposed.is_some() && (slot.is_none() || args.force) {
+                *slot = proposed;
+            }
+        }
+    }
+
+    let rendered = examples::render_expectations(&expectations);
+    if examples::parse_expectations(&current_expectations).ok() != Some(expectations) {
+        write(&expectations_path, &rendered, args.dry_run);
+    }
+
+    println!("---");
+    if args.dry_run {
+        println!("🎄 Dry run, nothing was written. Run without `--dry-run` to apply.");
+    } else {
+        println!(
+            "🎄 Type `cargo test day{:02}` to check your solution against the examples.",
+            args.day
+        );
+    }
+}
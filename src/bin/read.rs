@@ -2,23 +2,31 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::client::{get_puzzle_path, Client};
+use advent_of_code::{config, year};
+use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(year::default_year),
     })
 }
 
 fn main() {
+    if let Err(e) = config::init() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
@@ -27,20 +35,18 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    if let Ok(puzzle) = fs::read_to_string(get_puzzle_path(args.year, args.day)) {
+        println!("{}", puzzle);
+        return;
     }
 
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    let puzzle = Client::from_env(Some(args.year)).and_then(|client| client.get_puzzle(args.day));
+
+    match puzzle {
+        Ok(puzzle) => println!("{}", puzzle),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            process::exit(1);
+            eprintln!("Failed to read day {}: {}", args.day, e);
+            process::exit(e.exit_code());
         }
     }
 }
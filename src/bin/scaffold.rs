@@ -2,113 +2,269 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
-};
-
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+use advent_of_code::cache::{self, Cache, Fetched};
+use advent_of_code::client::{get_input_path, get_puzzle_path, Client};
+use advent_of_code::examples::{self, get_example_path, get_expectations_path};
+use advent_of_code::input::project_root;
+use advent_of_code::scaffold::{self, Action, Change};
+use advent_of_code::{answers, config, extract, template, year};
+use std::{fs, io, path::PathBuf, process};
+
+struct Args {
+    year: u16,
+    day: u8,
+    /// Answer types of both parts, `--type i64` or `--type i64,String`.
+    types: (String, String),
+    template: String,
+    /// Downloads the input and puzzle and extracts the examples from the puzzle.
+    download: bool,
+    force: bool,
+    dry_run: bool,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+fn parse_types(arg: &str) -> Result<(String, String), String> {
+    let types: Vec<&str> = arg.split(',').map(str::trim).collect();
+    let (one, two) = match types[..] {
+        [both] => (both, both),
+        [one, two] => (one, two),
+        _ => return Err(format!("expected one or two types, got \"{}\"", arg)),
+    };
+
+    match [one, two].into_iter().find(|t| !template::is_type_name(t)) {
+        Some(invalid) => Err(format!("\"{}\" is not a type", invalid)),
+        None => Ok((one.to_string(), two.to_string())),
+    }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let nom = args.contains("--nom");
+    Ok(Args {
+        download: args.contains(["-d", "--download"]),
+        force: args.contains("--force"),
+        dry_run: args.contains(["-n", "--dry-run"]),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(year::default_year),
+        types: args
+            .opt_value_from_fn(["-t", "--type"], parse_types)?
+            .unwrap_or_else(|| ("u32".into(), "u32".into())),
+        template: args
+            .opt_value_from_str("--template")?
+            .unwrap_or_else(|| if nom { "day-nom" } else { "day" }.into()),
+        day: args.free_from_str()?,
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Loads and renders a template, exiting on errors.
+fn render(file_name: &str, values: &[(&str, &str)]) -> String {
+    match template::load(file_name).and_then(|t| template::render(file_name, &t, values)) {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            eprintln!("Failed to render template: {}", e);
+            process::exit(1);
+        }
+    }
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+/// Downloads the input and the puzzle, reporting failures without stopping the scaffold.
+fn download(year: u16, day: u8, force: bool) {
+    let client = match Client::from_env(Some(year)) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Skipped the download: {}", e);
+            return;
+        }
+    };
+
+    let input_path = get_input_path(year, day);
+    let fetched = Cache::open(&cache::get_cache_dir())
+        .and_then(|mut cache| cache::fetch_input(&client, &mut cache, &input_path, day, force));
+    match fetched {
+        Ok(Fetched::Existing) => {}
+        Ok(_) => println!("Downloaded input \"{}\"", input_path.display()),
+        Err(e) => eprintln!("Failed to download the input: {}", e),
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    match client.download_puzzle(day) {
+        Ok(path) => println!("Downloaded puzzle \"{}\"", path.display()),
+        Err(e) => eprintln!("Failed to download the puzzle: {}", e),
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Example files and expectations proposed by the downloaded puzzle, if there is one.
+fn extract_examples(year: u16, day: u8) -> Option<(Vec<(PathBuf, String)>, String)> {
+    let markdown = fs::read_to_string(get_puzzle_path(year, day)).ok()?;
+    let extracted = extract::extract(&markdown);
+    if extracted.is_empty() {
+        return None;
+    }
+
+    let files = extracted
+        .iter()
+        .map(|e| (get_example_path(year, day, &e.name), e.input.clone()))
+        .collect();
+    let expectations = extracted
+        .into_iter()
+        .map(|e| (e.name, e.expected))
+        .collect();
+    Some((files, examples::render_expectations(&expectations)))
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Whether the expectations file has no expected answer yet, e.g. right after scaffolding.
+fn has_no_expectations(path: &PathBuf) -> bool {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| examples::parse_expectations(&content).ok())
+        .is_some_and(|expectations| {
+            expectations
+                .values()
+                .all(|answers| answers.part1.is_none() && answers.part2.is_none())
+        })
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn plan(args: &Args, values: &[(&str, &str)]) -> io::Result<Vec<Change>> {
+    let (year, day) = (args.year, args.day);
+    let year_dir = year::get_year_dir(year);
+    let year_path = year_dir.join("mod.rs");
+
+    // render everything up front, so a broken template does not leave a half scaffolded day.
+    let module = render(&format!("{}.rs", args.template), values);
+    let answers = render("answers.toml", values);
+
+    let mut changes = vec![Change::file(
+        year_dir.join(format!("day{:02}.rs", day)),
+        module,
+        args.force,
+    )];
+
+    let input_path = get_input_path(year, day);
+    if args.download && args.dry_run {
+        // an existing input is only downloaded again if it is broken, see `cache::fetch_input`.
+        let valid = fs::read_to_string(&input_path)
+            .is_ok_and(|input| cache::validate_input(&input).is_ok());
+        changes.push(Change::download(input_path, args.force || !valid));
+        changes.push(Change::download(get_puzzle_path(year, day), true));
+    } else {
+        changes.push(Change::new_file(input_path, String::new()));
+    }
+
+    let expectations_path = get_expectations_path(year, day);
+    match extract_examples(year, day) {
+        Some((files, expectations)) => {
+            for (path, input) in files {
+                changes.push(Change::file(path, input, args.force));
+            }
+            let force = args.force || has_no_expectations(&expectations_path);
+            changes.push(Change::file(expectations_path, expectations, force));
+        }
+        None => {
+            changes.push(Change::new_file(
+                get_example_path(year, day, examples::DEFAULT_EXAMPLE),
+                String::new(),
+            ));
+            changes.push(Change::new_file(
+                expectations_path,
+                render("examples.toml", values),
+            ));
+        }
+    }
+
+    changes.push(Change::new_file(
+        answers::get_answers_path(year, day),
+        answers,
+    ));
+
+    if year_path.exists() {
+        changes.push(Change::edit(year_path, |source| {
+            scaffold::register_day(source, day)
+        })?);
+    } else {
+        let year_module = render("year.rs", values);
+        let year_module = scaffold::register_day(&year_module, day).unwrap_or(year_module);
+        changes.push(Change::new_file(year_path, year_module));
+
+        let src = project_root().join("src");
+        changes.push(Change::edit(src.join("lib.rs"), |source| {
+            scaffold::register_year_module(source, year)
+        })?);
+        changes.push(Change::edit(src.join("days.rs"), |source| {
+            scaffold::register_year_days(source, year)
+        })?);
+    }
+
+    Ok(changes)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    if let Err(e) = config::init() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e @ pico_args::Error::Utf8ArgumentParsingFailed { .. }) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let day_padded = format!("{:02}", args.day);
+    let year_number = args.year.to_string();
+    let day_number = args.day.to_string();
+    let examples_dir = config::get().dir("examples", args.year);
+    let examples_dir = examples_dir
+        .strip_prefix(project_root())
+        .unwrap_or(&examples_dir)
+        .display()
+        .to_string();
+    let values = [
+        ("YEAR", year_number.as_str()),
+        ("DAY", day_number.as_str()),
+        ("DAY_PADDED", day_padded.as_str()),
+        ("PART_ONE_TYPE", args.types.0.as_str()),
+        ("PART_TWO_TYPE", args.types.1.as_str()),
+        ("EXAMPLES_DIR", examples_dir.as_str()),
+    ];
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
-
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
-        }
-    };
-
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
-        }
+    // in dry runs, the downloads are listed with the rest of the plan.
+    if args.download && !args.dry_run {
+        download(args.year, args.day, args.force);
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
+    let changes = match plan(&args, &values) {
+        Ok(changes) => changes,
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to read the day registry: {}", e);
             process::exit(1);
         }
-    }
+    };
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    for change in &changes {
+        println!("{}", change);
+        if args.dry_run {
+            continue;
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+        if let Err(e) = change.apply() {
+            eprintln!("Failed to write \"{}\": {}", change.path.display(), e);
             process::exit(1);
         }
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if args.dry_run {
+        println!("🎄 Dry run, nothing was written. Run without `--dry-run` to apply.");
+    } else if changes.iter().all(|change| change.action == Action::Keep) {
+        println!("🎄 Day {} is already scaffolded.", day_padded);
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            day_padded, args.year
+        );
+    }
 }
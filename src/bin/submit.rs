@@ -0,0 +1,131 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::Client;
+use advent_of_code::submit::{Guess, History, Outcome};
+use advent_of_code::{answers, config, days, input, year};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(year::default_year),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        answer: args.opt_free_from_str()?,
+    })
+}
+
+/// Runs the solution of a part against `src/<year>/inputs/NN.txt`.
+fn solve(year: u16, day: u8, part: u8) -> Result<String, String> {
+    let entry = days::get(year, day).ok_or_else(|| {
+        format!(
+            "Day {} of {} is not registered in `src/{}/mod.rs`.",
+            day, year, year
+        )
+    })?;
+    let solver = entry.solver(part).ok_or("Part needs to be 1 or 2.")?;
+    let input = input::try_read_file(year, "inputs", day).map_err(|e| e.to_string())?;
+
+    solver(&input)
+        .map_err(|e| format!("Could not parse the input: {}", e))?
+        .ok_or_else(|| format!("Day {} part {} returned no answer.", day, part))
+}
+
+fn main() {
+    if let Err(e) = config::init() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) if args.part == 1 || args.part == 2 => args,
+        _ => {
+            eprintln!("Need to specify a day and part (as integers). example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    let answer = match args.answer.clone() {
+        Some(answer) => answer,
+        None => match solve(args.year, args.day, args.part) {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+    };
+
+    let client = match Client::from_env(Some(args.year)) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(e.exit_code());
+        }
+    };
+
+    let mut history = match History::load(args.year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    if let Err(rejection) = history.check(client.year(), args.day, args.part, &answer) {
+        eprintln!("Not submitting \"{}\": {}", answer, rejection);
+        process::exit(1);
+    }
+
+    println!(
+        "Submitting \"{}\" for day {} part {}, {}...",
+        answer,
+        args.day,
+        args.part,
+        client.year()
+    );
+
+    let outcome = match client.submit(args.day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to submit: {}", e);
+            process::exit(e.exit_code());
+        }
+    };
+
+    if outcome.is_verdict() {
+        history.record(Guess::new(
+            client.year(),
+            args.day,
+            args.part,
+            &answer,
+            outcome.clone(),
+        ));
+        if let Err(e) = history.save() {
+            eprintln!("Failed to record the guess: {}", e);
+        }
+    }
+
+    println!("---");
+    println!("🎄 {}", outcome);
+
+    if outcome != Outcome::Correct {
+        process::exit(1);
+    }
+
+    match answers::record_answer(args.year, args.day, args.part, &answer) {
+        Ok(path) => println!("🎄 Recorded the answer in \"{}\".", path.display()),
+        Err(e) => eprintln!("Failed to record the answer: {}", e),
+    }
+}
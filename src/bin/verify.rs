@@ -0,0 +1,140 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{self, Verdict};
+use advent_of_code::runner::{self, PANIC_LINES};
+use advent_of_code::solution::{Day, Solver};
+use advent_of_code::{config, days, input, year};
+use std::process;
+
+struct Args {
+    year: u16,
+    day: Option<u8>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(year::default_year),
+        day: args.opt_free_from_str()?,
+    })
+}
+
+/// Verifies both parts of a day and prints one line per part.
+/// Returns the number of failed parts.
+fn verify_day(day: Day) -> usize {
+    let expected = match answers::read_answers(day.year, day.day) {
+        Ok(answers) => answers.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("Day {:02} | {}", day.day, e);
+            return 2;
+        }
+    };
+
+    let input = match input::try_read_file(day.year, "inputs", day.day) {
+        Ok(input) => input,
+        Err(e) => {
+            println!("Day {:02} | skipped, {}", day.day, e);
+            return 0;
+        }
+    };
+
+    let answers_path = answers::get_answers_path(day.year, day.day);
+    let answers_path = answers_path
+        .strip_prefix(input::project_root())
+        .unwrap_or(&answers_path);
+    let parts: [Solver; 2] = [day.part_one, day.part_two];
+    let mut failed = 0;
+
+    for (index, solver) in parts.iter().enumerate() {
+        let part = index as u8 + 1;
+        let actual = match runner::catch_panic(|| solver(&input)) {
+            Ok(Ok(actual)) => actual,
+            Ok(Err(e)) => {
+                println!(
+                    "Day {:02} | Part {} | FAIL, could not parse the input: {}",
+                    day.day, part, e
+                );
+                failed += 1;
+                continue;
+            }
+            Err(message) => {
+                println!(
+                    "Day {:02} | Part {} | FAIL, solution panicked: {}",
+                    day.day,
+                    part,
+                    runner::excerpt(&message, PANIC_LINES)
+                );
+                failed += 1;
+                continue;
+            }
+        };
+
+        match answers::verify(expected.get(part), actual.as_deref()) {
+            Verdict::Pass => println!("Day {:02} | Part {} | pass", day.day, part),
+            Verdict::Missing => println!(
+                "Day {:02} | Part {} | missing, no answer recorded in \"{}\"",
+                day.day,
+                part,
+                answers_path.display()
+            ),
+            Verdict::Fail { expected, actual } => {
+                failed += 1;
+                println!(
+                    "Day {:02} | Part {} | FAIL, expected \"{}\", got {}",
+                    day.day,
+                    part,
+                    expected,
+                    actual
+                        .map(|a| format!("\"{}\"", a))
+                        .unwrap_or_else(|| "no answer".into())
+                );
+            }
+        }
+    }
+
+    failed
+}
+
+fn main() {
+    if let Err(e) = config::init() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Day needs to be an integer. example: `cargo verify 7`");
+            process::exit(1);
+        }
+    };
+
+    let to_verify = match args.day {
+        Some(day) => match days::get(args.year, day) {
+            Some(entry) => vec![entry],
+            None => {
+                eprintln!(
+                    "Day {} of {} is not registered in `src/{}/mod.rs`.",
+                    day, args.year, args.year
+                );
+                process::exit(1);
+            }
+        },
+        None => days::all(args.year),
+    };
+
+    runner::capture_panics();
+
+    let failed: usize = to_verify.into_iter().map(verify_day).sum();
+
+    println!("---");
+    if failed > 0 {
+        println!("🎄 {} part(s) failed verification.", failed);
+        process::exit(1);
+    }
+    println!("🎄 All recorded answers match.");
+}
@@ -0,0 +1,342 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::client::{write_file, Client, ClientError};
+use crate::input::project_root;
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const CACHE_DIR: &str = ".aoc-cache";
+
+/// Why a downloaded or local input can not be a real puzzle input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidInput {
+    Empty,
+    NotLoggedIn,
+    Html,
+    /// Inputs always end with a newline, anything else was cut off.
+    Truncated,
+}
+
+impl Display for InvalidInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidInput::Empty => write!(f, "the input is empty"),
+            InvalidInput::NotLoggedIn => write!(f, "the input asks to log in"),
+            InvalidInput::Html => write!(f, "the input is an HTML page"),
+            InvalidInput::Truncated => write!(f, "the input does not end with a newline"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CacheError {
+    Client(ClientError),
+    InvalidInput(InvalidInput),
+    /// The cached copy does not match the checksum recorded when it was fetched.
+    Corrupt(PathBuf),
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, serde_json::Error),
+}
+
+impl CacheError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CacheError::Client(e) => e.exit_code(),
+            CacheError::InvalidInput(_) => 7,
+            CacheError::Corrupt(_) | CacheError::Io(..) | CacheError::Parse(..) => 6,
+        }
+    }
+}
+
+impl Display for CacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CacheError::Client(e) => write!(f, "{}", e),
+            CacheError::InvalidInput(e) => write!(f, "refusing to save the input: {}.", e),
+            CacheError::Corrupt(path) => {
+                write!(f, "cached input \"{}\" is corrupt.", path.display())
+            }
+            CacheError::Io(path, e) => {
+                write!(f, "could not access \"{}\": {}", path.display(), e)
+            }
+            CacheError::Parse(path, e) => {
+                write!(f, "could not parse \"{}\": {}", path.display(), e)
+            }
+        }
+    }
+}
+
+impl Error for CacheError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CacheError::Client(e) => Some(e),
+            CacheError::Io(_, e) => Some(e),
+            CacheError::Parse(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ClientError> for CacheError {
+    fn from(e: ClientError) -> Self {
+        CacheError::Client(e)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub checksum: String,
+    pub bytes: usize,
+    /// Seconds since the unix epoch.
+    pub fetched_at: u64,
+}
+
+/// Copies of every downloaded input, stored per year in `.aoc-cache/<year>/NN.txt`
+/// and indexed with a checksum in `.aoc-cache/index.json`.
+pub struct Cache {
+    dir: PathBuf,
+    pub entries: Vec<Entry>,
+}
+
+/// What [`fetch_input`] did to provide the input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// A valid input was already present and was kept.
+    Existing,
+    Downloaded,
+    /// The server could not be reached, the input was restored from the cache.
+    Restored,
+}
+
+pub fn get_cache_dir() -> PathBuf {
+    project_root().join(CACHE_DIR)
+}
+
+/// FNV-1a hash of the input, stable across platforms and Rust versions.
+pub fn checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+pub fn validate_input(input: &str) -> Result<(), InvalidInput> {
+    let start = input.trim_start().to_lowercase();
+
+    if input.trim().is_empty() {
+        Err(InvalidInput::Empty)
+    } else if input.contains("Please log in") {
+        Err(InvalidInput::NotLoggedIn)
+    } else if start.starts_with("<!doctype") || start.starts_with("<html") {
+        Err(InvalidInput::Html)
+    } else if !input.ends_with('\n') {
+        Err(InvalidInput::Truncated)
+    } else {
+        Ok(())
+    }
+}
+
+impl Cache {
+    pub fn open(dir: &Path) -> Result<Self, CacheError> {
+        let index_path = dir.join("index.json");
+        let entries = match fs::read_to_string(&index_path) {
+            Ok(content) => {
+                serde_json::from_str(&content).map_err(|e| CacheError::Parse(index_path, e))?
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(CacheError::Io(index_path, e)),
+        };
+
+        Ok(Cache {
+            dir: dir.to_path_buf(),
+            entries,
+        })
+    }
+
+    fn copy_path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{:02}.txt", day))
+    }
+
+    pub fn entry(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day)
+    }
+
+    /// Stores a copy of the input and records its checksum.
+    pub fn store(&mut self, year: u16, day: u8, input: &str) -> Result<(), CacheError> {
+        let path = self.copy_path(year, day);
+        write_file(&path, input).map_err(CacheError::from)?;
+
+        self.entries.retain(|e| e.year != year || e.day != day);
+        self.entries.push(Entry {
+            year,
+            day,
+            checksum: checksum(input),
+            bytes: input.len(),
+            fetched_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        });
+        self.entries.sort_by_key(|e| (e.year, e.day));
+
+        let index_path = self.dir.join("index.json");
+        let json = serde_json::to_string_pretty(&self.entries)
+            .map_err(|e| CacheError::Parse(index_path.clone(), e))?;
+        fs::write(&index_path, json + "\n").map_err(|e| CacheError::Io(index_path, e))
+    }
+
+    /// Reads the cached copy of an input, verifying it against the recorded checksum.
+    pub fn restore(&self, year: u16, day: u8) -> Result<Option<String>, CacheError> {
+        let Some(entry) = self.entry(year, day) else {
+            return Ok(None);
+        };
+
+        let path = self.copy_path(year, day);
+        let input = fs::read_to_string(&path).map_err(|e| CacheError::Io(path.clone(), e))?;
+
+        if checksum(&input) != entry.checksum {
+            return Err(CacheError::Corrupt(path));
+        }
+        Ok(Some(input))
+    }
+}
+
+/// Makes sure a valid input for `day` exists at `path`.
+///
+/// A valid input that is already present is kept unless `force` is set. Downloaded inputs
+/// are validated before they are written and copied to the cache. If the server can not be
+/// reached, the input is restored from the cache.
+pub fn fetch_input(
+    client: &Client,
+    cache: &mut Cache,
+    path: &Path,
+    day: u8,
+    force: bool,
+) -> Result<Fetched, CacheError> {
+    if !force {
+        if let Ok(existing) = fs::read_to_string(path) {
+            if validate_input(&existing).is_ok() {
+                return Ok(Fetched::Existing);
+            }
+        }
+    }
+
+    let input = match client.get_input(day) {
+        Ok(input) => input,
+        Err(e @ ClientError::Network { .. }) => match cache.restore(client.year(), day)? {
+            Some(input) => {
+                write_file(path, &input)?;
+                return Ok(Fetched::Restored);
+            }
+            None => return Err(e.into()),
+        },
+        Err(e) => return Err(e.into()),
+    };
+
+    validate_input(&input).map_err(CacheError::InvalidInput)?;
+    write_file(path, &input)?;
+    cache.store(client.year(), day, &input)?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn offline_client() -> Client {
+        Client::new("abc123", 2022).with_base_url("http://127.0.0.1:9")
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum("1\n2\n"), checksum("1\n2"));
+    }
+
+    #[test]
+    fn test_validate_input() {
+        assert_eq!(validate_input("1000\n2000\n"), Ok(()));
+        assert_eq!(validate_input("\n"), Err(InvalidInput::Empty));
+        assert_eq!(
+            validate_input(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            ),
+            Err(InvalidInput::NotLoggedIn)
+        );
+        assert_eq!(
+            validate_input("<!DOCTYPE html>\n<html></html>\n"),
+            Err(InvalidInput::Html)
+        );
+        assert_eq!(validate_input("1000\n20"), Err(InvalidInput::Truncated));
+    }
+
+    #[test]
+    fn test_store_and_restore() {
+        let dir = temp_dir("store");
+        let mut cache = Cache::open(&dir).unwrap();
+        cache.store(2022, 1, "1000\n").unwrap();
+        cache.store(2021, 1, "2000\n").unwrap();
+
+        let cache = Cache::open(&dir).unwrap();
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.restore(2022, 1).unwrap(), Some("1000\n".into()));
+        assert_eq!(cache.restore(2022, 2).unwrap(), None);
+
+        fs::write(dir.join("2022").join("01.txt"), "1001\n").unwrap();
+        assert!(matches!(
+            cache.restore(2022, 1),
+            Err(CacheError::Corrupt(_))
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_offline() {
+        let dir = temp_dir("fetch");
+        let input_path = dir.join("inputs").join("01.txt");
+        let mut cache = Cache::open(&dir.join("cache")).unwrap();
+        let client = offline_client();
+
+        assert!(matches!(
+            fetch_input(&client, &mut cache, &input_path, 1, false),
+            Err(CacheError::Client(ClientError::Network { .. }))
+        ));
+
+        cache.store(2022, 1, "1000\n").unwrap();
+        assert_eq!(
+            fetch_input(&client, &mut cache, &input_path, 1, false).unwrap(),
+            Fetched::Restored
+        );
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1000\n");
+
+        assert_eq!(
+            fetch_input(&client, &mut cache, &input_path, 1, false).unwrap(),
+            Fetched::Existing
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
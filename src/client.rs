@@ -0,0 +1,561 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config;
+use crate::submit::{parse_outcome, Outcome};
+use crate::year::{default_year, get_day_path};
+use regex::Regex;
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides [`DEFAULT_BASE_URL`], e.g. to point the client at a local mock server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Holds the session cookie. Takes precedence over the session files.
+pub const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+
+const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Identifies the tool to adventofcode.com, with the `repository` of `Cargo.toml`.
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+",
+    env!("CARGO_PKG_REPOSITORY"),
+    ")"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session cookie in the environment or the session files. Names the `session_file` of
+    /// `aoc.toml`, if one is configured.
+    MissingSession {
+        session_file: Option<PathBuf>,
+    },
+    /// The `session_file` configured in `aoc.toml` could not be read.
+    SessionFile {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The server did not accept the session cookie, it probably expired.
+    ExpiredSession,
+    NotUnlocked {
+        year: u16,
+        day: u8,
+    },
+    /// Any other unsuccessful status code.
+    Http {
+        url: String,
+        status: u16,
+    },
+    Network {
+        url: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    Filesystem {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The response did not look like an Advent of Code page.
+    UnexpectedResponse {
+        url: String,
+        excerpt: String,
+    },
+}
+
+impl ClientError {
+    /// Process exit code of the `download`, `read` and `submit` commands for this error.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ClientError::MissingSession { .. } | ClientError::SessionFile { .. } => 2,
+            ClientError::ExpiredSession => 3,
+            ClientError::NotUnlocked { .. } => 4,
+            ClientError::Network { .. } => 5,
+            ClientError::Filesystem { .. } => 6,
+            ClientError::UnexpectedResponse { .. } => 7,
+            ClientError::Http { .. } => 8,
+        }
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession {
+                session_file: Some(path),
+            } => write!(
+                f,
+                "no session cookie found. Set {} or save it to \"{}\".",
+                SESSION_ENV,
+                path.display()
+            ),
+            ClientError::MissingSession { session_file: None } => write!(
+                f,
+                "no session cookie found. Set {} or save it to \"~/{}\".",
+                SESSION_ENV, SESSION_FILE
+            ),
+            ClientError::SessionFile { path, source } => write!(
+                f,
+                "could not read the session cookie from \"{}\": {}",
+                path.display(),
+                source
+            ),
+            ClientError::ExpiredSession => write!(
+                f,
+                "the session cookie was not accepted, it probably expired. Copy a fresh one from your browser."
+            ),
+            ClientError::NotUnlocked { year, day } => {
+                write!(f, "day {} of {} is not unlocked yet.", day, year)
+            }
+            ClientError::Http { url, status } => {
+                write!(f, "request to \"{}\" failed with status {}.", url, status)
+            }
+            ClientError::Network { url, source } => {
+                write!(f, "request to \"{}\" failed: {}", url, source)
+            }
+            ClientError::Filesystem { path, source } => {
+                write!(f, "could not write \"{}\": {}", path.display(), source)
+            }
+            ClientError::UnexpectedResponse { url, excerpt } => {
+                write!(f, "could not make sense of the response from \"{}\": {}", url, excerpt)
+            }
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Network { source, .. } => Some(source.as_ref()),
+            ClientError::Filesystem { source, .. } | ClientError::SessionFile { source, .. } => {
+                Some(source)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Minimal Advent of Code client, authenticated with the session cookie of a logged-in browser.
+pub struct Client {
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl Client {
+    pub fn new(session: &str, year: u16) -> Self {
+        Client {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Builds a client from the environment: the session cookie is read from
+    /// `ADVENT_OF_CODE_SESSION`, `~/.adventofcode.session` or `~/.config/adventofcode.session`,
+    /// the base URL from `AOC_BASE_URL`. Without a year, the default year is used.
+    pub fn from_env(year: Option<u16>) -> Result<Self, ClientError> {
+        let session = read_session(config::get().session_file())?;
+        let client = Client::new(&session, year.unwrap_or_else(default_year));
+
+        Ok(match env::var(BASE_URL_ENV) {
+            Ok(base_url) if !base_url.is_empty() => client.with_base_url(&base_url),
+            _ => client,
+        })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+    }
+
+    fn read_response(
+        &self,
+        day: u8,
+        url: &str,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, ClientError> {
+        let network_error = |source: Box<dyn Error + Send + Sync>| ClientError::Network {
+            url: url.to_string(),
+            source,
+        };
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| network_error(Box::new(e))),
+            Err(ureq::Error::Status(404, _)) => Err(ClientError::NotUnlocked {
+                year: self.year,
+                day,
+            }),
+            Err(ureq::Error::Status(401 | 403, _)) => Err(ClientError::ExpiredSession),
+            // Inputs answer an unknown session with 400, or 500 if the cookie is malformed.
+            // Elsewhere, e.g. for answers, a 400 means the request itself was malformed.
+            Err(ureq::Error::Status(400 | 500, _)) if url.ends_with("/input") => {
+                Err(ClientError::ExpiredSession)
+            }
+            Err(ureq::Error::Status(status, _)) => Err(ClientError::Http {
+                url: url.to_string(),
+                status,
+            }),
+            Err(ureq::Error::Transport(e)) => Err(network_error(Box::new(e))),
+        }
+    }
+
+    fn get(&self, day: u8, url: &str) -> Result<String, ClientError> {
+        self.read_response(day, url, self.request("GET", url).call())
+    }
+
+    pub fn get_input(&self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(day));
+        let input = self.get(day, &url)?;
+
+        if input.contains("Please log in") {
+            return Err(ClientError::ExpiredSession);
+        }
+        Ok(input)
+    }
+
+    /// Fetches the puzzle description and converts it to markdown.
+    pub fn get_puzzle(&self, day: u8) -> Result<String, ClientError> {
+        let url = self.day_url(day);
+        let html = self.get(day, &url)?;
+
+        // Logged-out pages link to the login and hide part two.
+        if html.contains("/auth/login") {
+            return Err(ClientError::ExpiredSession);
+        }
+
+        puzzle_to_markdown(&html).ok_or_else(|| ClientError::UnexpectedResponse {
+            excerpt: excerpt(&html),
+            url,
+        })
+    }
+
+    /// Posts the answer of a part and reads the verdict from the response.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Outcome, ClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", &level), ("answer", answer)]);
+        let html = self.read_response(day, &url, response)?;
+
+        parse_outcome(&html).ok_or_else(|| ClientError::UnexpectedResponse {
+            excerpt: excerpt(&html),
+            url,
+        })
+    }
+
+    /// Fetches the puzzle description and writes it to `src/<year>/puzzles/NN.md`.
+    pub fn download_puzzle(&self, day: u8) -> Result<PathBuf, ClientError> {
+        let puzzle = self.get_puzzle(day)?;
+        let path = get_puzzle_path(self.year, day);
+        write_file(&path, &puzzle)?;
+        Ok(path)
+    }
+}
+
+pub fn get_input_path(year: u16, day: u8) -> PathBuf {
+    get_day_path(year, "inputs", day, "txt")
+}
+
+pub fn get_puzzle_path(year: u16, day: u8) -> PathBuf {
+    get_day_path(year, "puzzles", day, "md")
+}
+
+pub(crate) fn write_file(path: &Path, content: &str) -> Result<(), ClientError> {
+    let filesystem_error = |path: &Path, source| ClientError::Filesystem {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| filesystem_error(parent, e))?;
+    }
+    fs::write(path, content).map_err(|e| filesystem_error(path, e))
+}
+
+/// First characters of a response, to show along with an error.
+fn excerpt(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.char_indices().nth(120) {
+        Some((index, _)) => format!("{}...", &text[..index]),
+        None => text,
+    }
+}
+
+/// Reads the session cookie from the environment, `session_file` or the default session files.
+fn read_session(session_file: Option<PathBuf>) -> Result<String, ClientError> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session);
+        }
+    }
+
+    // An explicit session file replaces the default locations.
+    if let Some(path) = session_file {
+        return match fs::read_to_string(&path) {
+            Ok(session) if !session.trim().is_empty() => Ok(session),
+            Ok(_) => Err(ClientError::MissingSession {
+                session_file: Some(path),
+            }),
+            Err(source) => Err(ClientError::SessionFile { path, source }),
+        };
+    }
+
+    let Some(home) = env::var_os("HOME").map(PathBuf::from) else {
+        return Err(ClientError::MissingSession { session_file: None });
+    };
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".config"));
+
+    [
+        home.join(SESSION_FILE),
+        config_dir.join("adventofcode.session"),
+    ]
+    .iter()
+    .filter_map(|path| fs::read_to_string(path).ok())
+    .find(|session| !session.trim().is_empty())
+    .ok_or(ClientError::MissingSession { session_file: None })
+}
+
+/// Converts the `<main>` element of a puzzle page, i.e. both parts and submitted answers, to markdown.
+/// Returns `None` if the page has no puzzle description.
+pub fn puzzle_to_markdown(html: &str) -> Option<String> {
+    let main = Regex::new(r"(?s)<main>(.*)</main>").unwrap();
+    let content = main.captures(html)?.get(1)?.as_str();
+
+    if !content.contains("<article") {
+        return None;
+    }
+    Some(html2md::parse_html(content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves `responses` in order, one per connection, and returns the received requests.
+    fn mock_server(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+
+                let length = head
+                    .lines()
+                    .find_map(|l| {
+                        l.to_lowercase()
+                            .strip_prefix("content-length: ")
+                            .map(|v| v.to_string())
+                    })
+                    .map_or(0, |v| v.trim().parse().unwrap());
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+                head.push_str(&String::from_utf8(request_body).unwrap());
+                requests.push(head);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_get_input() {
+        let (base_url, server) = mock_server(vec![(200, "1000\n2000\n")]);
+        let client = Client::new("abc123\n", 2022).with_base_url(&base_url);
+
+        assert_eq!(client.get_input(1).unwrap(), "1000\n2000\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(requests[0]
+            .to_lowercase()
+            .contains("cookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn test_get_puzzle() {
+        let page = "<html><body><header>nav</header><main>\
+            <article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>\
+            <p>For example:</p><pre><code>1\n2\n</code></pre>\
+            <p>This is <code><em>3</em></code>.</p></article></main></body></html>";
+        let (base_url, server) = mock_server(vec![(200, page)]);
+        let client = Client::new("abc123", 2022).with_base_url(&base_url);
+
+        let markdown = client.get_puzzle(1).unwrap();
+        assert!(!markdown.contains("nav"));
+        assert!(markdown.contains("Day 1: Test"));
+        assert!(markdown.contains("```\n1\n2\n"));
+        assert!(markdown.contains("`*3*`"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1 HTTP/1.1"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let page = "<main><article><p>That's not the right answer; \
+            your answer is too low.</p></article></main>";
+        let (base_url, server) = mock_server(vec![(200, page)]);
+        let client = Client::new("abc123", 2022).with_base_url(&base_url);
+
+        assert_eq!(client.submit(1, 2, "42").unwrap(), Outcome::TooLow);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn test_expired_session() {
+        let (base_url, server) = mock_server(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                200,
+                "<main><a href=\"/2022/auth/login\">[Log In]</a><article></article></main>",
+            ),
+        ]);
+        let client = Client::new("abc123", 2022).with_base_url(&base_url);
+
+        assert!(matches!(
+            client.get_input(1),
+            Err(ClientError::ExpiredSession)
+        ));
+        assert!(matches!(
+            client.get_puzzle(1),
+            Err(ClientError::ExpiredSession)
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_rejected_submission() {
+        let (base_url, server) = mock_server(vec![(400, "Bad Request")]);
+        let client = Client::new("abc123", 2022).with_base_url(&base_url);
+
+        let error = client.submit(1, 1, "42").unwrap_err();
+        assert!(matches!(error, ClientError::Http { status: 400, .. }));
+        assert_eq!(error.exit_code(), 8);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_unexpected_response() {
+        let (base_url, server) = mock_server(vec![(200, "<html>maintenance</html>")]);
+        let client = Client::new("abc123", 2022).with_base_url(&base_url);
+
+        match client.submit(1, 1, "42") {
+            Err(e @ ClientError::UnexpectedResponse { .. }) => {
+                assert_eq!(e.exit_code(), 7);
+                assert!(e.to_string().contains("maintenance"));
+            }
+            other => panic!("expected an unexpected response, got {:?}", other),
+        }
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_network_error() {
+        let client = Client::new("abc123", 2022).with_base_url("http://127.0.0.1:9");
+        let error = client.get_input(1).unwrap_err();
+
+        assert!(matches!(error, ClientError::Network { .. }));
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn test_not_unlocked() {
+        let (base_url, server) = mock_server(vec![(404, "Not found")]);
+        let client = Client::new("abc123", 2022).with_base_url(&base_url);
+
+        assert!(matches!(
+            client.get_input(25),
+            Err(ClientError::NotUnlocked {
+                year: 2022,
+                day: 25
+            })
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_session_file() {
+        // the environment takes precedence over session files.
+        if env::var_os(SESSION_ENV).is_some() {
+            return;
+        }
+        let dir = env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("session");
+
+        let error = read_session(Some(path.clone())).unwrap_err();
+        assert!(matches!(&error, ClientError::SessionFile { path: p, .. } if *p == path));
+        assert_eq!(error.exit_code(), 2);
+
+        fs::write(&path, "\n").unwrap();
+        let error = read_session(Some(path.clone())).unwrap_err();
+        assert!(error.to_string().contains(&path.display().to_string()));
+
+        fs::write(&path, "abc123\n").unwrap();
+        assert_eq!(read_session(Some(path)).unwrap(), "abc123\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,214 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::BenchOptions;
+use crate::input::project_root;
+use crate::report::Format;
+use serde::Deserialize;
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+
+const CONFIG_FILE: &str = "aoc.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Project settings shared by all commands, read from `aoc.toml` in the crate root:
+///
+/// ```toml
+/// year = 2022
+/// session_file = "~/.adventofcode.session"
+/// format = "json"
+///
+/// [paths]
+/// inputs = "src/{year}/inputs"
+///
+/// [bench]
+/// samples = 20
+/// ```
+///
+/// Every setting is optional, command line flags take precedence.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Year used when `--year` is not passed and `AOC_YEAR` is not set.
+    pub year: Option<u16>,
+    /// File holding the session cookie, checked after `ADVENT_OF_CODE_SESSION`.
+    pub session_file: Option<PathBuf>,
+    /// Report format of `cargo all` when `--format` is not passed.
+    pub format: Option<Format>,
+    pub paths: Paths,
+    pub bench: BenchConfig,
+}
+
+/// Directories used by the commands, relative to the crate root. `{year}` is replaced with the year.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    pub inputs: String,
+    pub examples: String,
+    pub puzzles: String,
+    /// Templates used by `cargo scaffold`.
+    pub templates: String,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Paths {
+            inputs: "src/{year}/inputs".into(),
+            examples: "src/{year}/examples".into(),
+            puzzles: "src/{year}/puzzles".into(),
+            templates: "templates".into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchConfig {
+    pub warmup: Option<u32>,
+    pub samples: Option<u32>,
+    pub budget_ms: Option<u64>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(path, e) => {
+                write!(f, "could not read config \"{}\": {}", path.display(), e)
+            }
+            ConfigError::Parse(path, e) => {
+                write!(f, "could not parse config \"{}\": {}", path.display(), e)
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(_, e) => Some(e),
+            ConfigError::Parse(_, e) => Some(e),
+        }
+    }
+}
+
+pub fn get_config_path() -> PathBuf {
+    project_root().join(CONFIG_FILE)
+}
+
+/// Parses a config file. A missing file is the default config.
+pub fn load(path: &Path) -> Result<Config, ConfigError> {
+    match fs::read_to_string(path) {
+        Ok(content) => toml::from_str(&content).map_err(|e| ConfigError::Parse(path.into(), e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+        Err(e) => Err(ConfigError::Io(path.into(), e)),
+    }
+}
+
+/// Loads `aoc.toml` for the rest of the process. Binaries call this first, so a broken
+/// config is reported instead of silently falling back to the defaults.
+pub fn init() -> Result<&'static Config, ConfigError> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+    let config = load(&get_config_path())?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// The loaded config, or the defaults if `aoc.toml` can not be read.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| load(&get_config_path()).unwrap_or_default())
+}
+
+/// Expands a leading `~` to the home directory and resolves relative paths against the crate root.
+fn resolve(path: &Path) -> PathBuf {
+    if let Ok(rest) = path.strip_prefix("~") {
+        if let Some(home) = env::var_os("HOME") {
+            return PathBuf::from(home).join(rest);
+        }
+    }
+    project_root().join(path)
+}
+
+impl Config {
+    /// Directory of a per-day folder, e.g. `src/2022/inputs`. Folders without a setting
+    /// live in the year directory.
+    pub fn dir(&self, folder: &str, year: u16) -> PathBuf {
+        let template = match folder {
+            "inputs" => self.paths.inputs.clone(),
+            "examples" => self.paths.examples.clone(),
+            "puzzles" => self.paths.puzzles.clone(),
+            _ => format!("src/{{year}}/{}", folder),
+        };
+        resolve(Path::new(&template.replace("{year}", &year.to_string())))
+    }
+
+    pub fn templates_dir(&self) -> PathBuf {
+        resolve(Path::new(&self.paths.templates))
+    }
+
+    pub fn session_file(&self) -> Option<PathBuf> {
+        self.session_file.as_deref().map(resolve)
+    }
+
+    /// Benchmark options with the configured values in place of the defaults.
+    pub fn bench_options(&self) -> BenchOptions {
+        let defaults = BenchOptions::default();
+        BenchOptions {
+            warmup: self.bench.warmup.unwrap_or(defaults.warmup),
+            samples: self.bench.samples.unwrap_or(defaults.samples),
+            budget: self
+                .bench
+                .budget_ms
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.budget),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config: Config = toml::from_str(
+            r#"
+            year = 2021
+            format = "csv"
+
+            [paths]
+            inputs = "inputs/{year}"
+
+            [bench]
+            samples = 20
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2021));
+        assert_eq!(config.format, Some(Format::Csv));
+        assert!(config.dir("inputs", 2021).ends_with("inputs/2021"));
+        assert!(config.dir("examples", 2021).ends_with("src/2021/examples"));
+        assert!(config.dir("answers", 2021).ends_with("src/2021/answers"));
+        assert_eq!(config.bench_options().samples, 20);
+        assert_eq!(config.bench_options().warmup, 3);
+
+        assert!(toml::from_str::<Config>("yaer = 2021").is_err());
+        assert_eq!(toml::from_str::<Config>("").unwrap(), Config::default());
+    }
+}
@@ -0,0 +1,28 @@
+/*
+ * Registry of all solved days, across years.
+ * After scaffolding a new year, declare its module in `src/lib.rs` and add its `all()` here.
+ */
+use crate::solution::Day;
+
+fn registered() -> Vec<Day> {
+    [crate::y2022::all()].concat()
+}
+
+/// Years with at least one registered day, oldest first.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = registered().iter().map(|entry| entry.year).collect();
+    years.sort_unstable();
+    years.dedup();
+    years
+}
+
+pub fn all(year: u16) -> Vec<Day> {
+    registered()
+        .into_iter()
+        .filter(|entry| entry.year == year)
+        .collect()
+}
+
+pub fn get(year: u16, day: u8) -> Option<Day> {
+    all(year).into_iter().find(|entry| entry.day == day)
+}
@@ -0,0 +1,90 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{error::Error, fmt::Display};
+
+/// Puzzle input that could not be parsed, returned by [`Solution::parse`](crate::solution::Solution::parse).
+/// Lines and columns are numbered from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PuzzleError {
+    /// Set by the runner once the error leaves `parse`.
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    /// What the parser was looking for, e.g. `a number`.
+    pub expected: String,
+    /// The line the error is on.
+    pub snippet: String,
+}
+
+impl PuzzleError {
+    /// Points at `token`, a slice of `input` that could not be parsed, e.g. one of its lines.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+
+        PuzzleError {
+            day: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            snippet: input[line_start..].lines().next().unwrap_or("").to_string(),
+        }
+    }
+
+    /// Points at the end of `input`, for things that are missing altogether.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        PuzzleError::at(input, &input[input.len()..], expected)
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        PuzzleError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "    {}", self.snippet)?;
+        write!(f, "    {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for PuzzleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_error() {
+        let input = "move 1 from 2 to 3\nmove x from 1 to 2\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = PuzzleError::at(input, &line[5..], "a number").with_day(5);
+
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(
+            error.to_string(),
+            "day 05, line 2, column 6: expected a number\n    move x from 1 to 2\n         ^"
+        );
+
+        let error = PuzzleError::at_end(input, "a start");
+        assert_eq!(
+            (error.line, error.column, error.snippet.as_str()),
+            (3, 1, "")
+        );
+    }
+}
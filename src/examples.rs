@@ -0,0 +1,247 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{self, Answers};
+use crate::config;
+use crate::solution::Day;
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+/// Name of the example stored in `src/<year>/examples/NN.txt`.
+/// Other examples live in `src/<year>/examples/NN-<name>.txt`.
+pub const DEFAULT_EXAMPLE: &str = "default";
+
+#[derive(Clone, Debug)]
+pub struct Example {
+    pub name: String,
+    pub path: PathBuf,
+    pub input: String,
+    pub expected: Answers,
+}
+
+pub fn get_examples_dir(year: u16) -> PathBuf {
+    config::get().dir("examples", year)
+}
+
+/// Path of the expectations file, e.g. `src/2022/examples/09.toml`:
+///
+/// ```toml
+/// [default]
+/// part1 = 13
+/// part2 = 1
+///
+/// [larger]
+/// part2 = 36
+/// ```
+pub fn get_expectations_path(year: u16, day: u8) -> PathBuf {
+    get_examples_dir(year).join(format!("{:02}.toml", day))
+}
+
+/// Path of an example input, e.g. `src/2022/examples/09.txt` or `src/2022/examples/09-larger.txt`.
+pub fn get_example_path(year: u16, day: u8, name: &str) -> PathBuf {
+    if name == DEFAULT_EXAMPLE {
+        get_examples_dir(year).join(format!("{:02}.txt", day))
+    } else {
+        get_examples_dir(year).join(format!("{:02}-{}.txt", day, name))
+    }
+}
+
+pub fn parse_expectations(content: &str) -> Result<BTreeMap<String, Answers>, String> {
+    let table: toml::Table = content
+        .parse()
+        .map_err(|e: toml::de::Error| e.to_string())?;
+
+    table
+        .into_iter()
+        .map(|(name, value)| match value {
+            toml::Value::Table(answers) => match answers::parse_answers(&answers.to_string()) {
+                Ok(answers) => Ok((name, answers)),
+                Err(e) => Err(format!("[{}]: {}", name, e)),
+            },
+            _ => Err(format!("\"{}\" must be a table like [{}]", name, name)),
+        })
+        .collect()
+}
+
+/// Renders expectations in the format read by [`parse_expectations`], default example first.
+pub fn render_expectations(expectations: &BTreeMap<String, Answers>) -> String {
+    let mut names: Vec<&String> = expectations.keys().collect();
+    names.sort_by_key(|name| (name.as_str() != DEFAULT_EXAMPLE, name.as_str()));
+
+    names
+        .into_iter()
+        .map(|name| {
+            format!(
+                "[{}]\n{}",
+                name,
+                answers::render_answers(&expectations[name])
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn example_name(day: u8, file_name: &str) -> Option<String> {
+    let stem = file_name.strip_suffix(".txt")?;
+    let day_padded = format!("{:02}", day);
+
+    if stem == day_padded {
+        Some(DEFAULT_EXAMPLE.to_string())
+    } else {
+        stem.strip_prefix(&format!("{}-", day_padded))
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string())
+    }
+}
+
+/// Loads all examples of a day together with their expected answers.
+pub fn load_examples(year: u16, day: u8) -> Result<Vec<Example>, String> {
+    let expectations_path = get_expectations_path(year, day);
+    let mut expectations = match fs::read_to_string(&expectations_path) {
+        Ok(content) => parse_expectations(&content)
+            .map_err(|e| format!("\"{}\": {}", expectations_path.display(), e))?,
+        Err(_) => BTreeMap::new(),
+    };
+
+    let entries = fs::read_dir(get_examples_dir(year)).map_err(|e| e.to_string())?;
+    let mut examples = vec![];
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(name) = example_name(day, &file_name) else {
+            continue;
+        };
+
+        let path = entry.path();
+        let input = fs::read_to_string(&path)
+            .map_err(|e| format!("could not read \"{}\": {}", path.display(), e))?;
+
+        examples.push(Example {
+            expected: expectations.remove(&name).unwrap_or_default(),
+            name,
+            path,
+            input,
+        });
+    }
+
+    if let Some(name) = expectations.keys().next() {
+        return Err(format!(
+            "\"{}\" lists example [{}] but there is no matching example file.",
+            expectations_path.display(),
+            name
+        ));
+    }
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Runs one part against every example that has an expected answer for it.
+/// Panics with a summary of all mismatching examples. A part without any expected answer is skipped.
+pub fn assert_examples(day: &Day, part: u8) {
+    let examples = load_examples(day.year, day.day).unwrap_or_else(|e| panic!("{}", e));
+    let solver = day
+        .solver(part)
+        .unwrap_or_else(|| panic!("invalid part {}", part));
+
+    let checked: Vec<(&Example, &str)> = examples
+        .iter()
+        .filter_map(|example| Some((example, example.expected.get(part)?)))
+        .collect();
+
+    if checked.is_empty() {
+        eprintln!(
+            "{} day {:02} part {}: skipped, no expected answer in \"{}\"",
+            day.year,
+            day.day,
+            part,
+            get_expectations_path(day.year, day.day).display()
+        );
+        return;
+    }
+
+    let failures: Vec<String> = checked
+        .iter()
+        .filter_map(|&(example, expected)| {
+            let actual = solver(&example.input).unwrap_or_else(|e| {
+                panic!(
+                    "example [{}] (\"{}\"): could not parse the input: {}",
+                    example.name,
+                    example.path.display(),
+                    e
+                )
+            });
+
+            if actual.as_deref() == Some(expected) {
+                None
+            } else {
+                Some(format!(
+                    "example [{}] (\"{}\"): expected {:?}, got {:?}",
+                    example.name,
+                    example.path.display(),
+                    expected,
+                    actual
+                ))
+            }
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} day {:02} part {} failed on examples:\n{}",
+        day.year,
+        day.day,
+        part,
+        failures.join("\n")
+    );
+}
+
+/// Generates `test_part_one` and `test_part_two`, which check a solution against every
+/// example listed in its `src/<year>/examples/NN.toml`. Use it inside the `tests` module of a day.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn test_part_one() {
+            $crate::examples::assert_examples(&$crate::solution::Day::new::<$solution>(), 1);
+        }
+
+        #[test]
+        fn test_part_two() {
+            $crate::examples::assert_examples(&$crate::solution::Day::new::<$solution>(), 2);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_name() {
+        assert_eq!(example_name(9, "09.txt"), Some("default".into()));
+        assert_eq!(example_name(9, "09-larger.txt"), Some("larger".into()));
+        assert_eq!(example_name(9, "09-.txt"), None);
+        assert_eq!(example_name(9, "19.txt"), None);
+        assert_eq!(example_name(9, "09.toml"), None);
+    }
+
+    #[test]
+    fn test_parse_expectations() {
+        let expectations =
+            parse_expectations("[default]\npart1 = 13\n\n[larger]\npart2 = \"36\"\n").unwrap();
+
+        assert_eq!(expectations["default"].get(1), Some("13"));
+        assert_eq!(expectations["default"].get(2), None);
+        assert_eq!(expectations["larger"].get(2), Some("36"));
+
+        assert!(parse_expectations("part1 = 13").is_err());
+    }
+
+    #[test]
+    fn test_render_expectations() {
+        let content = "[default]\npart1 = 13\npart2 = \"MCD\"\n\n[larger]\npart2 = 36\n";
+        let expectations = parse_expectations(content).unwrap();
+        assert_eq!(render_expectations(&expectations), content);
+    }
+}
@@ -0,0 +1,281 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::examples::DEFAULT_EXAMPLE;
+use regex::Regex;
+
+/// An example found in the puzzle description, with the answers that could be matched to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtractedExample {
+    pub name: String,
+    pub input: String,
+    pub expected: Answers,
+}
+
+struct CodeBlock {
+    /// Last non-empty line of text before the opening fence.
+    intro: String,
+    content: String,
+}
+
+/// Splits the puzzle description into part one and, if unlocked, part two.
+fn split_parts(markdown: &str) -> (&str, Option<&str>) {
+    match markdown.find("--- Part Two ---") {
+        Some(index) => (&markdown[..index], Some(&markdown[index..])),
+        None => (markdown, None),
+    }
+}
+
+fn code_blocks(text: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut intro = String::new();
+    let mut current: Option<Vec<&str>> = None;
+
+    for line in text.lines() {
+        if line.trim_end() == "```" {
+            match current.take() {
+                Some(lines) => blocks.push(CodeBlock {
+                    intro: intro.clone(),
+                    content: format!("{}\n", lines.join("\n").trim_end_matches('\n')),
+                }),
+                None => current = Some(vec![]),
+            }
+        } else if let Some(lines) = current.as_mut() {
+            lines.push(line);
+        } else if !line.trim().is_empty() {
+            intro = line.to_string();
+        }
+    }
+
+    blocks
+}
+
+/// Lines of prose, i.e. everything outside of code blocks except the already submitted answer.
+fn prose_lines(text: &str) -> Vec<&str> {
+    let mut in_block = false;
+
+    text.lines()
+        .filter(|line| {
+            if line.trim_end() == "```" {
+                in_block = !in_block;
+                return false;
+            }
+            !in_block && !line.starts_with("Your puzzle answer was")
+        })
+        .collect()
+}
+
+/// Emphasized code spans, written as `` `*42*` `` or `` *`42`* ``, in order of appearance.
+fn emphasized(line: &str) -> Vec<String> {
+    let re = Regex::new(r"`\*([^*`]+)\*`|\*`([^*`]+)`\*").unwrap();
+
+    re.captures_iter(line)
+        .filter_map(|c| c.get(1).or_else(|| c.get(2)))
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+/// Picks the code block that most likely holds the example input.
+fn example_input(text: &str) -> Option<String> {
+    let blocks = code_blocks(text);
+    let intro_contains = |needle: &str| {
+        blocks
+            .iter()
+            .find(|b| b.intro.to_lowercase().contains(needle))
+    };
+
+    intro_contains("for example")
+        .or_else(|| intro_contains("your puzzle input"))
+        .or_else(|| blocks.first())
+        .map(|block| block.content.clone())
+}
+
+/// Bullet points of the form ``* `input`: ... `*answer*` ``, which list additional one-line examples.
+fn inline_examples(text: &str) -> Vec<(String, String)> {
+    let re = Regex::new(r"^\* `([^`\s*]+)`:").unwrap();
+
+    prose_lines(text)
+        .into_iter()
+        .filter_map(|line| {
+            let input = re.captures(line)?.get(1)?.as_str().to_string();
+            let answer = emphasized(line).pop()?;
+            Some((input, answer))
+        })
+        .collect()
+}
+
+/// The answer for the main example: the last emphasized value in the prose.
+/// Falls back to the last emphasized value in a bullet point.
+fn main_answer(text: &str) -> Option<String> {
+    let lines = prose_lines(text);
+    let (bullets, prose): (Vec<&str>, Vec<&str>) =
+        lines.into_iter().partition(|line| line.starts_with("* "));
+
+    prose
+        .iter()
+        .flat_map(|line| emphasized(line))
+        .last()
+        .or_else(|| bullets.iter().flat_map(|line| emphasized(line)).last())
+}
+
+/// Short, file-name friendly name for an inline example, from its first letters and digits.
+/// Falls back to `ex<index>` for inputs without any, and adds a suffix if the name is taken.
+fn inline_name(input: &str, index: usize, taken: &[ExtractedExample]) -> String {
+    let mut name = input
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .take(4)
+        .collect::<String>()
+        .to_lowercase();
+    if name.is_empty() {
+        name = format!("ex{}", index);
+    }
+
+    let is_taken = |name: &str| taken.iter().any(|e| e.name == name);
+    if !is_taken(&name) {
+        return name;
+    }
+    (2..)
+        .map(|suffix| format!("{}-{}", name, suffix))
+        .find(|name| !is_taken(name))
+        .unwrap()
+}
+
+fn set_answer(answers: &mut Answers, part: u8, answer: String) {
+    let slot = if part == 1 {
+        &mut answers.part1
+    } else {
+        &mut answers.part2
+    };
+
+    if slot.is_none() {
+        *slot = Some(answer);
+    }
+}
+
+/// Extracts examples and their expected answers from a puzzle description
+/// that was downloaded to `src/<year>/puzzles/NN.md`.
+///
+/// The default example is the first code block introduced with "For example" and gets the
+/// emphasized answer of each part. One-line examples listed as bullet points become named examples.
+pub fn extract(markdown: &str) -> Vec<ExtractedExample> {
+    let (part_one, part_two) = split_parts(markdown);
+
+    let Some(input) = example_input(part_one) else {
+        return vec![];
+    };
+
+    let mut examples = vec![ExtractedExample {
+        name: DEFAULT_EXAMPLE.to_string(),
+        input,
+        expected: Answers::default(),
+    }];
+
+    let parts = [(1, Some(part_one)), (2, part_two)];
+
+    for (part, text) in parts {
+        let Some(text) = text else {
+            continue;
+        };
+
+        for (input, answer) in inline_examples(text) {
+            match examples.iter_mut().find(|e| e.input.trim() == input) {
+                Some(example) => set_answer(&mut example.expected, part, answer),
+                None => {
+                    let mut expected = Answers::default();
+                    set_answer(&mut expected, part, answer);
+                    examples.push(ExtractedExample {
+                        name: inline_name(&input, examples.len(), &examples),
+                        input: format!("{}\n", input),
+                        expected,
+                    });
+                }
+            }
+        }
+
+        if let Some(answer) = main_answer(text) {
+            set_answer(&mut examples[0].expected, part, answer);
+        }
+    }
+
+    examples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r#"\--- Day 6: Tuning Trouble ---
+----------
+
+For example, suppose you receive the following datastream buffer:
+
+```
+mjqjpqmgbljsphdztnvjfqwrcgsmlb
+
+```
+
+In this case, your subroutine should report the value `*7*`.
+
+Here are a few more examples:
+
+* `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character `*5*`
+* `nppdvjthqldpwncqszvftbrmjlhg`: first marker after character `*6*`
+
+Your puzzle answer was `1640`.
+
+\--- Part Two ---
+----------
+
+* `mjqjpqmgbljsphdztnvjfqwrcgsmlb`: first marker after character `*19*`
+* `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character `*23*`
+"#;
+
+    #[test]
+    fn test_emphasized() {
+        assert_eq!(
+            emphasized("a total of `*6000*` Calories, this is *`24000`*."),
+            vec!["6000", "24000"]
+        );
+        assert!(emphasized("the value `7` is *not* emphasized").is_empty());
+    }
+
+    #[test]
+    fn test_example_input() {
+        let text = "You run:\n\n```\n$ update\n```\n\nFor example:\n\n```\n  [D]\n1 2\n\n```\n";
+        assert_eq!(example_input(text), Some("  [D]\n1 2\n".to_string()));
+    }
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(PUZZLE);
+
+        assert_eq!(examples.len(), 3);
+        assert_eq!(examples[0].name, "default");
+        assert_eq!(examples[0].input, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        assert_eq!(examples[0].expected.get(1), Some("7"));
+        assert_eq!(examples[0].expected.get(2), Some("19"));
+        assert_eq!(examples[1].name, "bvwb");
+        assert_eq!(examples[1].expected.get(1), Some("5"));
+        assert_eq!(examples[1].expected.get(2), Some("23"));
+        assert_eq!(examples[2].name, "nppd");
+        assert_eq!(examples[2].expected.get(2), None);
+    }
+
+    #[test]
+    fn test_inline_names() {
+        let text = "For example:\n\n```\nabcdef\n```\n\n\
+            * `abcdxx`: gives `*1*`\n\
+            * `abcdyy`: gives `*2*`\n\
+            * `#.#.`: gives `*3*`\n";
+        let names: Vec<String> = extract(text).into_iter().map(|e| e.name).collect();
+        assert_eq!(names, vec!["default", "abcd", "abcd-2", "ex3"]);
+    }
+
+    #[test]
+    fn test_extract_without_code_blocks() {
+        assert!(extract("\\--- Day 1 ---\n\nNothing to see here.").is_empty());
+    }
+}
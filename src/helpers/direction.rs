@@ -0,0 +1,129 @@
+use super::point::Point2;
+use std::str::FromStr;
+
+/// A direction on a grid, with `Up` towards the first row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Up, right, down and left.
+    pub const FOUR: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// All directions, clockwise from up.
+    pub const EIGHT: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Parses `U`, `R`, `D`, `L` or the arrows `^`, `>`, `v`, `<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// Change of `(x, y)` for one step in this direction.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn offset(self) -> Point2 {
+        let (dx, dy) = self.delta();
+        Point2::new(dx as i64, dy as i64)
+    }
+
+    /// Rotates clockwise by `eighths` of a full turn, counterclockwise if negative.
+    pub fn rotate(self, eighths: i32) -> Self {
+        let index = Direction::EIGHT.iter().position(|&d| d == self).unwrap() as i32;
+        Direction::EIGHT[(index + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Direction::FOUR.contains(&self)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Direction::from_char), chars.next()) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err(format!(
+                "unknown direction \"{}\", expected one of: U, R, D, L, ^, >, v, <",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!("<".parse(), Ok(Direction::Left));
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UR".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Direction::UpLeft.rotate(1), Direction::Up);
+        assert_eq!(Direction::Up.rotate(-1), Direction::UpLeft);
+        assert!(Direction::DownRight.is_diagonal());
+        assert_eq!(Direction::Left.offset(), Point2::new(-1, 0));
+    }
+}
@@ -0,0 +1,237 @@
+use super::grid::Grid;
+use crate::error::PuzzleError;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, newline, one_of, satisfy, space0},
+    combinator::{map_opt, map_res, opt, recognize},
+    error::ErrorKind,
+    multi::{many1, separated_list1},
+    sequence::{pair, preceded},
+    IResult, Parser,
+};
+use std::str::FromStr;
+
+fn expected(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number".into(),
+        ErrorKind::MapRes | ErrorKind::MapOpt => "a valid value".into(),
+        ErrorKind::Tag => "a keyword".into(),
+        ErrorKind::Char => "a character".into(),
+        ErrorKind::Alpha => "letters".into(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace".into(),
+        ErrorKind::Eof => "end of input".into(),
+        kind => kind.description().to_lowercase(),
+    }
+}
+
+fn error<'a>(input: &'a str, error: nom::Err<nom::error::Error<&'a str>>) -> PuzzleError {
+    match error {
+        nom::Err::Error(e) | nom::Err::Failure(e) => {
+            PuzzleError::at(input, e.input, expected(e.code))
+        }
+        nom::Err::Incomplete(_) => PuzzleError::at_end(input, "more input"),
+    }
+}
+
+/// Runs `parser` on the whole input. Fails with the position of the first character that
+/// could not be parsed, including leftovers after a successful parse. Trailing whitespace is allowed.
+pub fn parse_all<'a, T>(
+    mut parser: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+    input: &'a str,
+) -> Result<T, PuzzleError> {
+    let (rest, value) = parser.parse(input).map_err(|e| error(input, e))?;
+    let mut rest = rest.trim_start();
+    if rest.is_empty() {
+        return Ok(value);
+    }
+
+    // lists stop in front of an item that fails, so only leftovers are seen here. running the
+    // parser again from the start of that line reports what the failing item expected.
+    loop {
+        let offset = input.len() - rest.len();
+        if offset > 0 && !input[..offset].ends_with('\n') {
+            return Err(PuzzleError::at(input, rest, "end of line"));
+        }
+
+        match parser.parse(rest) {
+            Ok((next, _)) if next.len() < rest.len() && !next.trim_start().is_empty() => {
+                rest = next.trim_start();
+            }
+            Ok(_) => return Err(PuzzleError::at(input, rest, "end of input")),
+            Err(e) => return Err(error(input, e)),
+        }
+    }
+}
+
+/// An unsigned number, e.g. `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// A number with an optional sign, e.g. `-7` or `+3`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// Every number in `line`, ignoring the text around them, e.g. `[3, -7, 2]` for
+/// `x=3, y=-7 at 2`. A `-` only counts as a sign when it does not follow a digit, so
+/// ranges like `2-4` are two positive numbers.
+pub fn numbers<T: FromStr>(line: &str) -> Vec<T> {
+    let bytes = line.as_bytes();
+    let mut numbers = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let negative = start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_digit());
+
+        let number = if negative {
+            line[start - 1..i]
+                .parse()
+                .or_else(|_| line[start..i].parse())
+        } else {
+            line[start..i].parse()
+        };
+        numbers.extend(number.ok());
+    }
+    numbers
+}
+
+/// Blocks of lines separated by blank lines, without the line breaks around them.
+pub fn split_paragraphs(input: &str) -> Vec<&str> {
+    input
+        .split("\n\n")
+        .map(|paragraph| paragraph.trim_matches('\n'))
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect()
+}
+
+/// One `item` per line.
+pub fn lines<'a, O>(
+    item: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(newline, item)
+}
+
+/// One `item` per block of lines, with blank lines between the blocks.
+pub fn paragraphs<'a, O>(
+    item: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(newline, newline), item)
+}
+
+/// A labeled value such as `  Test: divisible by 23`, skipping the indentation and the label.
+pub fn field<'a, O>(
+    label: &'static str,
+    value: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    preceded(pair(space0, tag(label)), preceded(space0, value))
+}
+
+/// A character map with the same number of cells on every line. `cell` returns `None` for
+/// characters that are not allowed.
+pub fn grid<'a, T>(
+    cell: impl Fn(char) -> Option<T> + Copy,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    map_res(
+        separated_list1(newline, many1(map_opt(satisfy(|c| c != '\n'), cell))),
+        Grid::from_rows,
+    )
+}
+
+/// A comma separated list such as `79, 98`.
+pub fn list<'a, O>(
+    item: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(char(','), space0), item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::sequence::separated_pair;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(unsigned::<u32>("42 apples"), Ok((" apples", 42)));
+        assert!(unsigned::<u32>("-4").is_err());
+        assert_eq!(signed::<i64>("-7,"), Ok((",", -7)));
+        assert_eq!(signed::<i64>("+3"), Ok(("", 3)));
+
+        assert_eq!(numbers::<i32>("x=3, y=-7 at 2"), vec![3, -7, 2]);
+        assert_eq!(numbers::<u32>("2-4,6-8"), vec![2, 4, 6, 8]);
+        assert_eq!(numbers::<u32>("move -3"), vec![3]);
+        assert_eq!(numbers::<u8>("no numbers"), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        assert_eq!(split_paragraphs("a\nb\n\nc\n"), vec!["a\nb", "c"]);
+
+        let sums = paragraphs(lines(unsigned::<u32>)).map(|blocks| {
+            blocks
+                .iter()
+                .map(|block| block.iter().sum())
+                .collect::<Vec<u32>>()
+        });
+        assert_eq!(parse_all(sums, "1\n2\n\n3\n"), Ok(vec![3, 3]));
+    }
+
+    #[test]
+    fn test_field() {
+        let (rest, items) =
+            field("Starting items:", list(unsigned::<u32>))("  Starting items: 79, 98\n").unwrap();
+        assert_eq!(items, vec![79, 98]);
+        assert_eq!(rest, "\n");
+    }
+
+    #[test]
+    fn test_grid() {
+        let digits = grid(|c| c.to_digit(10));
+        let parsed = parse_all(digits, "12\n34\n").unwrap();
+        assert_eq!(parsed.get((1, 1)), Some(&4));
+
+        assert!(parse_all(grid(|c| c.to_digit(10)), "12\n3").is_err());
+    }
+
+    #[test]
+    fn test_parse_all_errors() {
+        let error = parse_all(lines(signed::<i32>), "1\n2\nx3\n").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError {
+                day: None,
+                line: 3,
+                column: 1,
+                expected: "a number".into(),
+                snippet: "x3".into(),
+            }
+        );
+
+        let pair = separated_pair(unsigned::<u32>, char(','), unsigned::<u32>);
+        let error = parse_all(lines(pair), "1,2\n3;4\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a character");
+
+        let error = parse_all(paragraphs(lines(unsigned::<u32>)), "1\n\n2\n3x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 2));
+        assert_eq!(error.expected, "end of line");
+
+        let error = parse_all(preceded(tag("addx "), signed::<i32>), "addx y").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert_eq!(error.expected, "a number");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected a number\n    addx y\n         ^"
+        );
+    }
+}
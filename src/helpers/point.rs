@@ -0,0 +1,161 @@
+use super::grid::Pos;
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A point on a 2D plane, with `y` growing downwards like the rows of a puzzle input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// Component-wise arithmetic, distances and stepping shared by both point types.
+macro_rules! impl_point {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl $point {
+            pub const ORIGIN: $point = $point { $($axis: 0),+ };
+
+            pub const fn new($($axis: i64),+) -> Self {
+                $point { $($axis),+ }
+            }
+
+            /// Sum of the distances along every axis.
+            pub fn manhattan(self, other: $point) -> u64 {
+                0 $(+ self.$axis.abs_diff(other.$axis))+
+            }
+
+            /// Largest distance along a single axis, e.g. `1` for diagonal neighbors.
+            pub fn chebyshev(self, other: $point) -> u64 {
+                0 $(.max(self.$axis.abs_diff(other.$axis)))+
+            }
+
+            /// The sign of every component, i.e. a step of at most 1 along every axis.
+            pub fn signum(self) -> Self {
+                $point { $($axis: self.$axis.signum()),+ }
+            }
+
+            /// Moves one step towards `target`, diagonally if needed.
+            pub fn step_towards(self, target: $point) -> Self {
+                self + (target - self).signum()
+            }
+        }
+
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, factor: i64) -> $point {
+                $point { $($axis: self.$axis * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl Point2 {
+    /// The position of this point in a [`Grid`](super::grid::Grid), if it has no negative coordinate.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<Pos> for Point2 {
+    fn from((x, y): Pos) -> Self {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut p = Point2::new(1, 2) + Point2::new(3, -1);
+        assert_eq!(p, Point2::new(4, 1));
+        p -= Point2::new(1, 1);
+        assert_eq!(p * 2, Point2::new(6, 0));
+        assert_eq!(-p, Point2::new(-3, 0));
+        assert_eq!(
+            Point3::new(1, 2, 3) - Point3::new(1, 1, 1),
+            Point3::new(0, 1, 2)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point2::new(0, 0), Point2::new(3, -4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point3::new(1, 1, 1).manhattan(Point3::ORIGIN), 3);
+        assert_eq!(Point3::new(1, -5, 1).chebyshev(Point3::ORIGIN), 5);
+    }
+
+    #[test]
+    fn test_step_towards() {
+        let tail = Point2::new(0, 0);
+        assert_eq!(tail.step_towards(Point2::new(2, 1)), Point2::new(1, 1));
+        assert_eq!(tail.step_towards(Point2::new(0, -2)), Point2::new(0, -1));
+        assert_eq!(tail.step_towards(tail), tail);
+        assert_eq!(Point2::new(2, -1).to_pos(), None);
+        assert_eq!(Point2::from((2, 1)).to_pos(), Some((2, 1)));
+    }
+}
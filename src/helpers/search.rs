@@ -0,0 +1,284 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Outcome of a search: the cost of every reached node and the way it was reached.
+#[derive(Clone, Debug)]
+pub struct Search<N, C> {
+    /// Lowest known cost of every reached node.
+    pub costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    /// The first node that matched the goal, if any.
+    pub goal: Option<N>,
+    /// Number of nodes taken from the queue and expanded.
+    pub expanded: usize,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Search {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+            expanded: 0,
+        }
+    }
+
+    /// Cost of the goal, if it was reached.
+    pub fn cost(&self) -> Option<C> {
+        self.cost_to(self.goal.as_ref()?)
+    }
+
+    pub fn cost_to(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Nodes from a start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// Nodes from a start to `node`, both included. Returns `None` if `node` was not reached.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Number of reached nodes, including the ones still queued when the goal was found.
+    pub fn visited(&self) -> usize {
+        self.costs.len()
+    }
+}
+
+/// Breadth-first search from `start` until a node matches `goal`. The cost is the number of steps.
+///
+/// Pass `|_| false` as `goal` to reach every node.
+pub fn bfs<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], neighbors, goal)
+}
+
+/// Breadth-first search from all `starts` at once, e.g. to find the closest of many starts.
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        search.expanded += 1;
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let cost = search.costs[&node] + 1;
+        for next in neighbors(&node) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Cheapest path search over weighted edges, from `start` until a node matches `goal`.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first([start], neighbors, |_| C::default(), goal)
+}
+
+/// Like [`dijkstra`], guided by `heuristic`. The heuristic must never overestimate the
+/// remaining cost, or the path found may not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first([start], neighbors, heuristic, goal)
+}
+
+/// Expands the queued node with the lowest cost plus heuristic first. `C::default()` is the
+/// cost of the starts.
+fn best_first<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    // the heap holds indices into `queued`, so nodes don't need to be `Ord`.
+    // ties are expanded in the order they were queued.
+    let mut queued: Vec<(N, C)> = vec![];
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        search.costs.insert(start.clone(), C::default());
+        heap.push(Reverse((heuristic(&start), queued.len())));
+        queued.push((start, C::default()));
+    }
+
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let (node, cost) = queued[index].clone();
+        // a cheaper way to this node was queued after this entry.
+        if search.costs[&node] < cost {
+            continue;
+        }
+
+        search.expanded += 1;
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if search
+                .costs
+                .get(&next)
+                .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+            search.costs.insert(next.clone(), next_cost);
+            search.parents.insert(next.clone(), node.clone());
+            heap.push(Reverse((next_cost + heuristic(&next), queued.len())));
+            queued.push((next, next_cost));
+        }
+    }
+
+    search
+}
+
+/// Reverses the edges between `nodes`, so a search can run from the goal back to the starts.
+/// Every node maps to the nodes that have an edge towards it.
+pub fn reverse_graph<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> HashMap<N, Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut reversed: HashMap<N, Vec<N>> = HashMap::new();
+    for node in nodes {
+        for next in neighbors(&node) {
+            reversed.entry(next).or_default().push(node.clone());
+        }
+    }
+    reversed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of nodes `0..10`, where each node links to the next two.
+    fn neighbors(&node: &u32) -> Vec<u32> {
+        [node + 1, node + 2]
+            .into_iter()
+            .filter(|&n| n < 10)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(0, neighbors, |&n| n == 7);
+        assert_eq!(search.cost(), Some(4));
+        assert_eq!(search.path(), Some(vec![0, 1, 3, 5, 7]));
+        assert_eq!(search.path_to(&42), None);
+
+        let search = bfs(0, neighbors, |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.visited(), 10);
+        assert_eq!(search.expanded, 10);
+
+        let search = bfs_multi([4, 8], neighbors, |&n| n == 9);
+        assert_eq!(search.cost(), Some(1));
+        assert_eq!(search.path(), Some(vec![8, 9]));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // jumping two nodes costs 5, stepping to the next one 1.
+        let weighted = |&node: &u32| {
+            neighbors(&node)
+                .into_iter()
+                .map(move |next| (next, if next == node + 1 { 1 } else { 5 }))
+        };
+
+        let search = dijkstra(0, weighted, |&n| n == 6);
+        assert_eq!(search.cost(), Some(6));
+        assert_eq!(search.path(), Some(vec![0, 1, 2, 3, 4, 5, 6]));
+
+        let search = astar(0, weighted, |&n| 6u32.saturating_sub(n), |&n| n == 6);
+        assert_eq!(search.cost(), Some(6));
+        assert!(search.expanded <= 7);
+    }
+
+    #[test]
+    fn test_reverse_graph() {
+        let reversed = reverse_graph(0..10, neighbors);
+        assert_eq!(reversed[&5], vec![3, 4]);
+        assert_eq!(reversed.get(&0), None);
+
+        let search = bfs(
+            9,
+            |n| reversed.get(n).cloned().unwrap_or_default(),
+            |&n| n == 0,
+        );
+        assert_eq!(search.cost(), Some(5));
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::year::get_day_path;
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where to load a puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/<year>/<folder>/NN.txt`, e.g. `src/2022/inputs/05.txt`.
+    Folder(String),
+    /// An explicit file path, relative paths resolve against the current directory.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses the value of an `--input` flag, `-` reads from stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    File(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::File(path, e) => {
+                write!(f, "could not open input file \"{}\": {}", path.display(), e)
+            }
+            InputError::Stdin(e) => write!(f, "could not read input from stdin: {}", e),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::File(_, e) | InputError::Stdin(e) => Some(e),
+        }
+    }
+}
+
+/// Root of the crate, so file lookups work no matter where the binary is started from.
+pub fn project_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+pub fn get_file_path(year: u16, folder: &str, day: u8) -> PathBuf {
+    get_day_path(year, folder, day, "txt")
+}
+
+pub fn try_read_file(year: u16, folder: &str, day: u8) -> Result<String, InputError> {
+    let path = get_file_path(year, folder, day);
+    fs::read_to_string(&path).map_err(|e| InputError::File(path, e))
+}
+
+pub fn read_input(source: &InputSource, year: u16, day: u8) -> Result<String, InputError> {
+    match source {
+        InputSource::Folder(folder) => try_read_file(year, folder, day),
+        InputSource::File(path) => {
+            fs::read_to_string(path).map_err(|e| InputError::File(path.clone(), e))
+        }
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+            Ok(input)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_read_file() {
+        assert!(try_read_file(2022, "examples", 1)
+            .unwrap()
+            .starts_with("1000"));
+
+        let error = try_read_file(2022, "examples", 99).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("examples"), "{}", message);
+        assert!(message.contains("99.txt"), "{}", message);
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn test_input_source_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("big.txt"),
+            InputSource::File(PathBuf::from("big.txt"))
+        );
+    }
+}
@@ -2,47 +2,593 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::baseline;
+use advent_of_code::bench::{self, BenchOptions, BenchResult};
+use advent_of_code::error::PuzzleError;
+use advent_of_code::input::{self, InputSource};
+use advent_of_code::record::{self, PartResult, Status};
+use advent_of_code::report::{self, Format};
+use advent_of_code::runner::{self, DaySelection, Run, RunOptions};
+use advent_of_code::solution::Day;
+use advent_of_code::{config, days, year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{
+    fs, panic,
+    path::{Path, PathBuf},
+    process,
+    time::Duration,
+};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+/// What to run: a single day, or the days of `cargo all`, e.g. `3..7`.
+enum Target {
+    Day(u8),
+    Days(DaySelection),
+}
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+struct Args {
+    year: u16,
+    target: Target,
+    only_solved: bool,
+    run: RunOptions,
+    fail_fast: bool,
+    input: Option<InputSource>,
+    example: bool,
+    part: Option<u8>,
+    format: Format,
+    output: Option<PathBuf>,
+    bench: Option<BenchOptions>,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let config = config::get();
+    let defaults = config.bench_options();
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    let save_baseline: Option<String> = args.opt_value_from_str("--save-baseline")?;
+    let baseline: Option<String> = args.opt_value_from_str("--baseline")?;
+    // saving or comparing baselines requires timings, so both imply `--bench`.
+    let bench = args.contains("--bench") || save_baseline.is_some() || baseline.is_some();
+    let bench_options = BenchOptions {
+        warmup: args
+            .opt_value_from_str("--warmup")?
+            .unwrap_or(defaults.warmup),
+        samples: args
+            .opt_value_from_str("--samples")?
+            .unwrap_or(defaults.samples),
+        budget: args
+            .opt_value_from_str("--budget-ms")?
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.budget),
+    };
+
+    let run = RunOptions {
+        parallel: args.opt_value_from_str("--parallel")?.unwrap_or(1),
+        timeout: args.opt_value_from_fn("--timeout", |s| {
+            s.parse::<f64>().map(Duration::from_secs_f64)
+        })?,
+        wait_for_timed_out: false,
+    };
+
+    Ok(Args {
+        only_solved: args.contains("--only-solved"),
+        fail_fast: args.contains("--fail-fast"),
+        run,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(year::default_year),
+        format: args
+            .opt_value_from_str(["-f", "--format"])?
+            .or(config.format)
+            .unwrap_or(Format::Text),
+        output: args.opt_value_from_str(["-o", "--output"])?,
+        input: args.opt_value_from_fn(["-i", "--input"], |s| {
+            Ok::<_, std::convert::Infallible>(InputSource::from_arg(s))
+        })?,
+        example: args.contains(["-e", "--example"]),
+        part: args.opt_value_from_str(["-p", "--part"])?,
+        bench: bench.then_some(bench_options),
+        save_baseline,
+        baseline,
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        target: parse_target(args.opt_free_from_str()?)?,
+    })
+}
+
+fn parse_target(arg: Option<String>) -> Result<Target, pico_args::Error> {
+    let Some(arg) = arg else {
+        return Ok(Target::Days(DaySelection::all()));
+    };
+
+    if let Ok(day) = arg.parse() {
+        return Ok(Target::Day(day));
+    }
+
+    match arg.parse() {
+        Ok(days) => Ok(Target::Days(days)),
+        Err(cause) => Err(pico_args::Error::Utf8ArgumentParsingFailed { value: arg, cause }),
+    }
+}
+
+const BOTH_PARTS: [u8; 2] = [1, 2];
+
+/// Parses the input of a day once and calls `run_part` for each of `parts` with a solver that
+/// reuses it, so timing a part leaves parsing out. No part runs if the input can not be parsed.
+fn solve_day<T>(
+    day: &Day,
+    input: &str,
+    parts: &[u8],
+    run_part: impl Fn(u8, &dyn Fn(&str) -> Option<String>) -> T,
+) -> Result<Vec<T>, PuzzleError> {
+    let parsed = (day.parse)(input)?;
+    Ok(parts
+        .iter()
+        .map(|&part| run_part(part, &|_: &str| parsed.solve(part)))
+        .collect())
+}
+
+/// Why a day of `cargo all` has no results, with an explanation for the user.
+struct Skipped {
+    status: Status,
+    message: String,
+    /// Why the input could not be parsed, for [`Status::ParseError`].
+    error: Option<PuzzleError>,
+}
+
+impl Skipped {
+    fn new(status: Status, message: String) -> Self {
+        Skipped {
+            status,
+            message,
+            error: None,
+        }
+    }
+}
+
+/// Result of a part in `cargo all`, with or without `--bench`.
+trait DayResult: Send + 'static {
+    /// Stands in for a part of a day that has no results.
+    fn skipped(day: u8, part: u8, skipped: &Skipped) -> Self;
+}
+
+impl DayResult for PartResult {
+    fn skipped(day: u8, part: u8, skipped: &Skipped) -> Self {
+        match &skipped.error {
+            Some(error) => PartResult::parse_error(day, part, error),
+            None => PartResult::failed(day, part, skipped.status),
+        }
+    }
+}
+
+impl DayResult for BenchResult {
+    fn skipped(day: u8, part: u8, skipped: &Skipped) -> Self {
+        match &skipped.error {
+            Some(error) => BenchResult::parse_error(day, part, error),
+            None => BenchResult::unsolved(day, part),
+        }
+    }
+}
+
+/// Days of `cargo all`, without unsolved days if `--only-solved` is passed.
+fn selected_days(args: &Args) -> Vec<u8> {
+    let Target::Days(selection) = &args.target else {
+        return vec![];
+    };
+
+    selection
+        .days()
+        .iter()
+        .copied()
+        .filter(|&day| {
+            !args.only_solved
+                || (days::get(args.year, day).is_some()
+                    && input::get_file_path(args.year, input_folder(args), day).exists())
+        })
+        .collect()
+}
+
+/// Folder `cargo all` reads the days from, `examples` with `--example`.
+fn input_folder(args: &Args) -> &'static str {
+    if args.example {
+        "examples"
+    } else {
+        "inputs"
+    }
+}
+
+/// Whether a JSON or CSV report goes to stdout, so nothing else may be printed there.
+fn report_on_stdout(args: &Args) -> bool {
+    args.output.is_none() && args.format != Format::Text
+}
+
+/// Runs both parts of a day without printing. Fails with the reason a day can not run.
+fn run_parts<T>(
+    year: u16,
+    day: u8,
+    folder: &str,
+    run_part: impl Fn(u8, u8, &dyn Fn(&str) -> Option<String>, &str) -> T,
+) -> Result<Vec<T>, Skipped> {
+    let Some(entry) = days::get(year, day) else {
+        if !module_path(year, day).exists() {
+            return Err(Skipped::new(Status::Unsolved, "Not solved.".to_string()));
+        }
+        return Err(Skipped::new(
+            Status::NotBuilt,
+            format!(
+                "Not built: \"{}\" is not registered in \"{}\".",
+                relative(&module_path(year, day)),
+                relative(&year::get_year_dir(year).join("mod.rs"))
+            ),
+        ));
+    };
+
+    let Ok(input) = input::try_read_file(year, folder, day) else {
+        let path = relative(&input::get_file_path(year, folder, day));
+        let message = if folder == "examples" {
+            format!("Missing example \"{}\".", path)
+        } else {
+            format!(
+                "Missing input \"{}\", run `cargo download {}` to fetch it.",
+                path, day
+            )
+        };
+        return Err(Skipped::new(Status::MissingInput, message));
+    };
+
+    solve_day(&entry, &input, &BOTH_PARTS, |part, solver| {
+        run_part(day, part, solver, &input)
+    })
+    .map_err(|e| Skipped {
+        status: Status::ParseError,
+        message: format!("Could not parse the input: {}", e),
+        error: Some(e),
+    })
+}
+
+fn module_path(year: u16, day: u8) -> PathBuf {
+    year::get_year_dir(year).join(format!("day{:02}.rs", day))
+}
+
+fn relative(path: &Path) -> String {
+    let path = path.strip_prefix(input::project_root()).unwrap_or(path);
+    path.display().to_string()
+}
+
+/// Splits the outcome of a day into its results, or why it has none.
+fn classify<T>(run: Run<Result<Vec<T>, Skipped>>) -> Result<Vec<T>, Skipped> {
+    match run {
+        Run::Done(result) => result,
+        Run::Panicked(message) => Err(Skipped::new(
+            Status::Panicked,
+            format!(
+                "Panicked: {}",
+                runner::excerpt(&message, runner::PANIC_LINES)
+            ),
+        )),
+        Run::TimedOut(timeout) => Err(Skipped::new(
+            Status::TimedOut,
+            format!("Timed out after {:.2?}.", timeout),
+        )),
+    }
+}
+
+/// Splits off what the solvers of a day printed, see [`advent_of_code::capture_solver_output`].
+fn split_output<T>(run: Run<(T, String)>) -> (Run<T>, String) {
+    match run {
+        Run::Done((result, output)) => (Run::Done(result), output),
+        Run::Panicked(message) => (Run::Panicked(message), String::new()),
+        Run::TimedOut(timeout) => (Run::TimedOut(timeout), String::new()),
+    }
+}
+
+/// Prints why a day has no results. Returns whether the run should go on.
+///
+/// With `quiet`, only problems are printed, to stderr and prefixed with the day.
+fn report_failure(day: u8, status: Status, message: &str, quiet: bool, fail_fast: bool) -> bool {
+    match (quiet, status.is_failure()) {
+        (false, false) => println!("{}", message),
+        (false, true) => eprintln!("{}", message),
+        (true, _) if status != Status::Unsolved => eprintln!("Day {:02}: {}", day, message),
+        (true, _) => {}
+    }
+
+    if !(fail_fast && status.is_failure()) {
+        return true;
+    }
+    eprintln!("Stopping at day {:02} because of `--fail-fast`.", day);
+    false
+}
+
+/// Results of the days of `cargo all`.
+struct AllDays<T> {
+    results: Vec<T>,
+    /// Whether `--fail-fast` stopped the run.
+    stopped: bool,
+    /// Whether a day panicked, timed out, could not be parsed or is not built.
+    broken: bool,
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+/// Runs the selected days of `cargo all` and reports days that have no results.
+/// `show` is called for every day first, with what its solutions printed and its results.
+fn run_selected<T: DayResult>(
+    args: &Args,
+    options: RunOptions,
+    quiet: bool,
+    run_part: impl Fn(u8, u8, &dyn Fn(&str) -> Option<String>, &str) -> T + Send + Sync + 'static,
+    mut show: impl FnMut(u8, &str, Option<&[T]>),
+) -> AllDays<T> {
+    let year = args.year;
+    let folder = input_folder(args);
+    let mut all = AllDays {
+        results: vec![],
+        stopped: false,
+        broken: false,
+    };
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    runner::run_days(
+        &selected_days(args),
+        options,
+        // what solvers print is kept until their day is shown, `--parallel` would mix it up.
+        move |day| {
+            advent_of_code::capture_solver_output(|| run_parts(year, day, folder, &run_part))
+        },
+        |day, run| {
+            let (run, output) = split_output(run);
+            match classify(run) {
+                Ok(day_results) => {
+                    show(day, &output, Some(&day_results));
+                    all.results.extend(day_results);
+                    true
                 }
+                Err(skipped) => {
+                    show(day, &output, None);
+                    all.results.extend(
+                        BOTH_PARTS
+                            .iter()
+                            .map(|&part| T::skipped(day, part, &skipped)),
+                    );
+                    all.broken |= skipped.status.is_failure();
+                    all.stopped = !report_failure(
+                        day,
+                        skipped.status,
+                        &skipped.message,
+                        quiet,
+                        args.fail_fast,
+                    );
+                    !all.stopped
+                }
+            }
+        },
+    );
+
+    all
+}
+
+fn write_report(rendered: String, output: Option<PathBuf>) {
+    match output {
+        Some(path) => match fs::write(&path, rendered) {
+            Ok(_) => println!("🎄 Wrote report to \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("Failed to write report to \"{}\": {}", path.display(), e);
+                process::exit(1);
+            }
+        },
+        None => print!("{}", rendered),
+    }
+}
+
+fn run_single(year: u16, day: u8, source: InputSource, parts: &[u8], bench: Option<BenchOptions>) {
+    let day = match days::get(year, day) {
+        Some(day) => day,
+        None => {
+            eprintln!(
+                "Day {} of {} is not registered in `src/{}/mod.rs`.",
+                day, year, year
             );
+            process::exit(1);
+        }
+    };
+
+    let input = match input::read_input(&source, year, day.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to load input: {}", e);
+            process::exit(1);
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let solved = panic::catch_unwind(|| {
+        solve_day(&day, &input, parts, |part, solver| match bench {
+            Some(options) => {
+                advent_of_code::solve!(day.day, part, solver, &input, bench = options);
+            }
+            None => {
+                advent_of_code::solve!(day.day, part, solver, &input);
             }
         })
-        .sum();
+    });
+
+    match solved {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => {
+            eprintln!("Could not parse the input: {}", e);
+            process::exit(1);
+        }
+        // the panic hook has printed the message already.
+        Err(_) => process::exit(1),
+    }
+}
+
+fn bench_all(options: BenchOptions, args: Args) {
+    let year = args.year;
+    // every part runs many times, what solutions print would bury the table.
+    advent_of_code::set_solver_output(false);
+    // concurrent days would distort each other's timings, so `--parallel` is ignored and
+    // a timed out day has to finish before the next one is benchmarked.
+    let run_options = RunOptions {
+        parallel: 1,
+        wait_for_timed_out: true,
+        ..args.run
+    };
+
+    let all = run_selected(
+        &args,
+        run_options,
+        true,
+        move |day, part, solver, input| bench::bench_part(day, part, solver, input, &options),
+        |_, _, _| {},
+    );
+    let results = all.results;
+
+    write_report(report::render_bench(&results, args.format), args.output);
+
+    if all.stopped {
+        process::exit(1);
+    }
+
+    if let Some(name) = &args.save_baseline {
+        match baseline::save(name, year, &results) {
+            Ok(path) => println!("🎄 Saved baseline to \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("Failed to save baseline: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if let Some(name) = &args.baseline {
+        let saved = match baseline::load(name, year) {
+            Ok(saved) => saved,
+            Err(e) => {
+                eprintln!("Failed to load baseline: {}", e);
+                process::exit(1);
+            }
+        };
+
+        let comparisons = baseline::compare(&saved, &results, args.threshold);
+        print!("{}", baseline::render_comparison(name, &comparisons));
+
+        let regressions = comparisons.iter().filter(|c| c.regressed).count();
+        if regressions > 0 {
+            eprintln!(
+                "{} part(s) regressed by more than {}% against baseline \"{}\".",
+                regressions, args.threshold, name
+            );
+            process::exit(1);
+        }
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+    if all.broken {
+        process::exit(1);
+    }
+}
+
+fn run_all(args: Args) {
+    // machine-readable reports on stdout must not be mixed with the human output.
+    let quiet = report_on_stdout(&args);
+    advent_of_code::set_solver_output(!quiet);
+
+    let all = run_selected(
+        &args,
+        args.run,
+        quiet,
+        |day, part, solver, input| record::run_part(day, part, solver, input),
+        |day, output, day_results| {
+            if quiet {
+                return;
+            }
+
+            println!("----------");
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("----------");
+            print!("{}", output);
+            for result in day_results.into_iter().flatten() {
+                println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+                record::print_result(result);
+            }
+        },
     );
+
+    if !quiet {
+        let total_ns = record::total_duration_ns(&all.results);
+
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total_ns as f64 / 1_000_000_f64,
+            ANSI_RESET
+        );
+        println!(
+            "{}Days:{} {}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            record::summary(&all.results)
+        );
+    }
+
+    if quiet || args.output.is_some() {
+        write_report(report::render(&all.results, args.format), args.output);
+    }
+
+    if all.stopped || all.broken {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    if let Err(e) = config::init() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "example: `cargo solve 7 -- --input big.txt` or `cargo all -- --format json`"
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Target::Day(day) = args.target {
+        let source = match (args.input, args.example) {
+            (Some(_), true) => {
+                eprintln!("`--input` and `--example` can not be combined.");
+                process::exit(1);
+            }
+            (Some(source), false) => source,
+            (None, true) => InputSource::Folder("examples".into()),
+            (None, false) => InputSource::Folder("inputs".into()),
+        };
+
+        let parts = match args.part {
+            None => BOTH_PARTS.to_vec(),
+            Some(part @ (1 | 2)) => vec![part],
+            Some(part) => {
+                eprintln!("Part needs to be 1 or 2, got {}.", part);
+                process::exit(1);
+            }
+        };
+
+        run_single(args.year, day, source, &parts, args.bench);
+        return;
+    }
+
+    if args.input.is_some() || args.part.is_some() {
+        eprintln!("`--input` and `--part` need a day. example: `cargo solve 7 -- --input big.txt --part 1`");
+        process::exit(1);
+    }
+
+    // panics are reported with their message once the day is done.
+    runner::capture_panics();
+
+    match args.bench {
+        Some(options) => bench_all(options, args),
+        None => run_all(args),
+    }
 }
@@ -0,0 +1,255 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::error::PuzzleError;
+use crate::{ANSI_ITALIC, ANSI_RESET};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Panicked,
+    TimedOut,
+    MissingInput,
+    /// The input could not be parsed, see [`PuzzleError`].
+    ParseError,
+    /// The day has a module in `src/<year>` that is not registered, so it is not part of the build.
+    NotBuilt,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+            Status::MissingInput => "missing_input",
+            Status::ParseError => "parse_error",
+            Status::NotBuilt => "not_built",
+        }
+    }
+
+    /// Whether the day is broken, as opposed to not solved yet.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Panicked | Status::TimedOut | Status::ParseError | Status::NotBuilt
+        )
+    }
+}
+
+/// Outcome of running a single part, as emitted by `solve!`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration_ns: u128,
+    pub status: Status,
+    /// Why the input could not be parsed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// What a solver returns: an answer, `None` for unsolved parts, or an error from parsing.
+pub trait Outcome {
+    fn into_answer(self) -> Result<Option<String>, PuzzleError>;
+}
+
+impl<T: Display> Outcome for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, PuzzleError> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<T: Display> Outcome for Result<Option<T>, PuzzleError> {
+    fn into_answer(self) -> Result<Option<String>, PuzzleError> {
+        self.map(|answer| answer.map(|answer| answer.to_string()))
+    }
+}
+
+impl PartResult {
+    pub fn new(day: u8, part: u8, answer: Option<String>, elapsed: Duration) -> Self {
+        let status = match answer {
+            Some(_) => Status::Solved,
+            None => Status::Unsolved,
+        };
+
+        PartResult {
+            day,
+            part,
+            answer,
+            duration_ns: elapsed.as_nanos(),
+            status,
+            error: None,
+        }
+    }
+
+    /// A part that did not run, e.g. because the day panicked.
+    pub fn failed(day: u8, part: u8, status: Status) -> Self {
+        PartResult {
+            day,
+            part,
+            answer: None,
+            duration_ns: 0,
+            status,
+            error: None,
+        }
+    }
+
+    /// A part whose input could not be parsed.
+    pub fn parse_error(day: u8, part: u8, error: &PuzzleError) -> Self {
+        PartResult {
+            error: Some(error.to_string()),
+            ..PartResult::failed(day, part, Status::ParseError)
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns as u64)
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.status == Status::Solved
+    }
+}
+
+/// Runs a single part and records its answer and timing without printing anything.
+pub fn run_part<O: Outcome>(
+    day: u8,
+    part: u8,
+    func: impl FnOnce(&str) -> O,
+    input: &str,
+) -> PartResult {
+    let timer = Instant::now();
+    let answer = func(input).into_answer();
+    let elapsed = timer.elapsed();
+    match answer {
+        Ok(answer) => PartResult::new(day, part, answer, elapsed),
+        Err(e) => PartResult::parse_error(day, part, &e),
+    }
+}
+
+pub fn print_result(result: &PartResult) {
+    if let Some(error) = &result.error {
+        eprintln!("Could not parse the input: {}", error);
+        return;
+    }
+
+    match &result.answer {
+        Some(answer) => {
+            println!(
+                "{} {}(elapsed: {:.2?}){}",
+                answer,
+                ANSI_ITALIC,
+                result.elapsed(),
+                ANSI_RESET
+            );
+        }
+        None => {
+            println!("not solved.");
+        }
+    }
+}
+
+/// Sums the timings of solved parts, in nanoseconds.
+pub fn total_duration_ns(results: &[PartResult]) -> u128 {
+    results
+        .iter()
+        .filter(|result| result.is_solved())
+        .map(|result| result.duration_ns)
+        .sum()
+}
+
+/// Counts days per status, e.g. `12 solved, 1 unsolved, 1 panicked`. A day takes the status
+/// of its first part that is not solved.
+pub fn summary(results: &[PartResult]) -> String {
+    let mut days: BTreeMap<u8, Status> = BTreeMap::new();
+    for result in results {
+        let status = days.entry(result.day).or_insert(result.status);
+        if *status == Status::Solved {
+            *status = result.status;
+        }
+    }
+
+    let mut counts: BTreeMap<Status, usize> = BTreeMap::from([(Status::Solved, 0)]);
+    for status in days.into_values() {
+        *counts.entry(status).or_default() += 1;
+    }
+
+    counts
+        .iter()
+        .map(|(status, count)| format!("{} {}", count, status.as_str().replace('_', " ")))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_emits_record() {
+        let solved = crate::solve!(1, 1, |input: &str| Some(input.len()), "abc");
+        assert_eq!(solved.day, 1);
+        assert_eq!(solved.part, 1);
+        assert_eq!(solved.answer, Some("3".to_string()));
+        assert_eq!(solved.status, Status::Solved);
+
+        let unsolved = crate::solve!(1, 2, |_: &str| None::<u32>, "abc");
+        assert_eq!(unsolved.answer, None);
+        assert_eq!(unsolved.status, Status::Unsolved);
+
+        let failed = crate::solve!(
+            1,
+            2,
+            |input: &str| -> Result<Option<u32>, PuzzleError> {
+                Err(PuzzleError::at(input, &input[1..], "a number"))
+            },
+            "abc"
+        );
+        assert_eq!(failed.status, Status::ParseError);
+        assert!(failed.error.unwrap().starts_with("line 1, column 2"));
+    }
+
+    #[test]
+    fn test_total_duration_ns() {
+        let results = vec![
+            PartResult::new(1, 1, Some("1".into()), Duration::from_nanos(74)),
+            PartResult::new(1, 2, Some("2".into()), Duration::from_micros(755)),
+            PartResult::new(2, 1, None, Duration::from_millis(10)),
+            PartResult::new(2, 2, Some("elapsed: 3ms)".into()), Duration::from_secs(1)),
+        ];
+
+        assert_eq!(total_duration_ns(&results), 1_000_755_074);
+    }
+
+    #[test]
+    fn test_summary() {
+        let results = vec![
+            PartResult::new(1, 1, Some("1".into()), Duration::ZERO),
+            PartResult::new(1, 2, Some("2".into()), Duration::ZERO),
+            PartResult::new(2, 1, Some("1".into()), Duration::ZERO),
+            PartResult::new(2, 2, None, Duration::ZERO),
+            PartResult::failed(3, 1, Status::Panicked),
+            PartResult::failed(3, 2, Status::Panicked),
+            PartResult::failed(4, 1, Status::MissingInput),
+            PartResult::failed(4, 2, Status::MissingInput),
+        ];
+
+        assert_eq!(
+            summary(&results),
+            "1 solved, 1 unsolved, 1 panicked, 1 missing input"
+        );
+        assert_eq!(summary(&[]), "0 solved");
+    }
+}
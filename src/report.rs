@@ -0,0 +1,251 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::{nanos, BenchResult};
+use crate::record::{self, PartResult};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Write, str::FromStr};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    parts: &'a [PartResult],
+    total_duration_ns: u128,
+}
+
+#[derive(Serialize)]
+struct JsonBenchReport<'a> {
+    benchmarks: &'a [BenchResult],
+}
+
+pub fn render(results: &[PartResult], format: Format) -> String {
+    match format {
+        Format::Text => render_text(results),
+        Format::Json => render_json(results),
+        Format::Csv => render_csv(results),
+    }
+}
+
+fn render_text(results: &[PartResult]) -> String {
+    let mut out = String::new();
+    for result in results {
+        let _ = writeln!(
+            out,
+            "Day {:02} | Part {} | {} | {:.2?} | {}",
+            result.day,
+            result.part,
+            result.answer.as_deref().unwrap_or("-"),
+            result.elapsed(),
+            result.status.as_str()
+        );
+    }
+    let _ = writeln!(
+        out,
+        "Total: {:.2}ms",
+        record::total_duration_ns(results) as f64 / 1_000_000_f64
+    );
+    let _ = writeln!(out, "Days: {}", record::summary(results));
+    out
+}
+
+fn render_json(results: &[PartResult]) -> String {
+    let report = JsonReport {
+        parts: results,
+        total_duration_ns: record::total_duration_ns(results),
+    };
+    // serializing plain structs with string keys can not fail.
+    serde_json::to_string_pretty(&report).unwrap() + "\n"
+}
+
+fn render_csv(results: &[PartResult]) -> String {
+    let mut out = String::from("day,part,answer,duration_ns,status\n");
+    for result in results {
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{}",
+            result.day,
+            result.part,
+            escape_csv(result.answer.as_deref().unwrap_or("")),
+            result.duration_ns,
+            result.status.as_str()
+        );
+    }
+    out
+}
+
+pub fn render_bench(results: &[BenchResult], format: Format) -> String {
+    match format {
+        Format::Text => render_bench_text(results),
+        Format::Json => {
+            serde_json::to_string_pretty(&JsonBenchReport {
+                benchmarks: results,
+            })
+            .unwrap()
+                + "\n"
+        }
+        Format::Csv => render_bench_csv(results),
+    }
+}
+
+fn render_bench_text(results: &[BenchResult]) -> String {
+    let mut out = format!(
+        "{:<6} {:<6} {:>12} {:>12} {:>12} {:>12} {:>8}\n",
+        "Day", "Part", "Median", "Min", "Mean", "Std. dev", "Samples"
+    );
+    for result in results {
+        match &result.stats {
+            Some(stats) => {
+                let _ = writeln!(
+                    out,
+                    "{:<6} {:<6} {:>12} {:>12} {:>12} {:>12} {:>8}",
+                    format!("{:02}", result.day),
+                    result.part,
+                    format!("{:.2?}", nanos(stats.median_ns)),
+                    format!("{:.2?}", nanos(stats.min_ns)),
+                    format!("{:.2?}", nanos(stats.mean_ns)),
+                    format!("{:.2?}", nanos(stats.stddev_ns)),
+                    stats.samples
+                );
+            }
+            None => {
+                let _ = writeln!(
+                    out,
+                    "{:<6} {:<6} {:>12}",
+                    format!("{:02}", result.day),
+                    result.part,
+                    match result.error {
+                        Some(_) => "parse error",
+                        None => "not solved",
+                    }
+                );
+            }
+        }
+    }
+    out
+}
+
+fn render_bench_csv(results: &[BenchResult]) -> String {
+    let mut out = String::from("day,part,answer,samples,min_ns,median_ns,mean_ns,stddev_ns\n");
+    for result in results {
+        let answer = escape_csv(result.answer.as_deref().unwrap_or(""));
+        match &result.stats {
+            Some(stats) => {
+                let _ = writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{}",
+                    result.day,
+                    result.part,
+                    answer,
+                    stats.samples,
+                    stats.min_ns,
+                    stats.median_ns,
+                    stats.mean_ns,
+                    stats.stddev_ns
+                );
+            }
+            None => {
+                let _ = writeln!(out, "{},{},{},0,,,,", result.day, result.part, answer);
+            }
+        }
+    }
+    out
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult::new(1, 1, Some("24000".into()), Duration::from_micros(120)),
+            PartResult::new(1, 2, None, Duration::from_nanos(50)),
+            PartResult::new(5, 1, Some("C,\"M\"Z".into()), Duration::from_millis(2)),
+        ]
+    }
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(
+            render(&results(), Format::Csv),
+            "day,part,answer,duration_ns,status\n\
+             1,1,24000,120000,solved\n\
+             1,2,,50,unsolved\n\
+             5,1,\"C,\"\"M\"\"Z\",2000000,solved\n"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&results(), Format::Json)).unwrap();
+
+        assert_eq!(json["total_duration_ns"], 2_120_000);
+        assert_eq!(json["parts"][0]["day"], 1);
+        assert_eq!(json["parts"][0]["answer"], "24000");
+        assert_eq!(json["parts"][1]["answer"], serde_json::Value::Null);
+        assert_eq!(json["parts"][1]["status"], "unsolved");
+    }
+
+    #[test]
+    fn test_render_bench_csv() {
+        let results = vec![
+            BenchResult {
+                day: 6,
+                part: 1,
+                answer: Some("7".into()),
+                stats: crate::bench::Stats::from_samples(&[
+                    Duration::from_nanos(10),
+                    Duration::from_nanos(20),
+                ]),
+                error: None,
+            },
+            BenchResult::unsolved(6, 2),
+        ];
+
+        assert_eq!(
+            render_bench(&results, Format::Csv),
+            "day,part,answer,samples,min_ns,median_ns,mean_ns,stddev_ns\n\
+             6,1,7,2,10,15,15,5\n\
+             6,2,,0,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,384 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap},
+    panic,
+    str::FromStr,
+    sync::{mpsc, Arc, Once},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+/// Lines of a panic message shown by `cargo all` and `cargo verify`, see [`excerpt`].
+pub const PANIC_LINES: usize = 10;
+
+/// Solutions run on their own threads, give them as much stack as the main thread.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// Whether this thread runs a job of [`run_days`], whose panics are recorded.
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Days selected on the command line, e.g. `3..7`, `3..=7` or `1,5,10..`.
+///
+/// Ranges follow Rust syntax: `3..7` runs days 3 to 6.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaySelection(Vec<u8>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        DaySelection((1..=25).collect())
+    }
+
+    pub fn days(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |s: &str| -> Result<u8, String> {
+            match s.trim().parse() {
+                Ok(day @ 1..=25) => Ok(day),
+                _ => Err(format!("\"{}\" is not a day between 1 and 25", s)),
+            }
+        };
+
+        let mut days = vec![];
+        for item in s.split(',') {
+            let range = match item.split_once("..") {
+                None => day(item).map(|d| d..=d)?,
+                Some((start, end)) => {
+                    let start = if start.is_empty() { 1 } else { day(start)? };
+                    match end.strip_prefix('=') {
+                        Some(end) => start..=day(end)?,
+                        None if end.is_empty() => start..=25,
+                        None => start..=day(end)?.saturating_sub(1),
+                    }
+                }
+            };
+            if range.is_empty() {
+                return Err(format!("\"{}\" is an empty range", item));
+            }
+            days.extend(range);
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(DaySelection(days))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RunOptions {
+    /// Number of days running at the same time.
+    pub parallel: usize,
+    /// Days that run longer are reported as timed out. Their thread is abandoned.
+    pub timeout: Option<Duration>,
+    /// Wait for the thread of a timed out day to finish before starting the next day, so it
+    /// does not compete with it, e.g. while benchmarking.
+    pub wait_for_timed_out: bool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            parallel: 1,
+            timeout: None,
+            wait_for_timed_out: false,
+        }
+    }
+}
+
+/// Outcome of running the job of a single day.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Run<T> {
+    Done(T),
+    /// The panic message, with its location.
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+/// Wraps the panic hook once, so panics inside [`run_days`] are recorded instead of printed.
+/// Panics anywhere else still go to the previous hook.
+pub fn capture_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURING.with(Cell::get) {
+                return previous(info);
+            }
+
+            let message = payload_message(info.payload());
+            let message = match info.location() {
+                Some(location) => format!("{} at {}", message, location),
+                None => message,
+            };
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Shortens a long panic message, e.g. a `Debug` dump of the input, to its first `max_lines` lines.
+pub fn excerpt(message: &str, max_lines: usize) -> String {
+    let lines: Vec<&str> = message.lines().collect();
+    if lines.len() <= max_lines {
+        return message.to_string();
+    }
+    format!(
+        "{}\n... ({} more lines)",
+        lines[..max_lines].join("\n"),
+        lines.len() - max_lines
+    )
+}
+
+/// Runs `job` and returns its result, or the message it panicked with, including its location.
+/// Call [`capture_panics`] first, or the panic is printed as well.
+pub fn catch_panic<T>(job: impl FnOnce() -> T) -> Result<T, String> {
+    // a panicking job is abandoned, nothing observes state it left behind.
+    CAPTURING.with(|capturing| capturing.set(true));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(job));
+    CAPTURING.with(|capturing| capturing.set(false));
+
+    result.map_err(|payload| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| payload_message(payload.as_ref()))
+    })
+}
+
+/// Runs `job` and catches its panic, if any.
+fn run_job<T>(job: impl FnOnce() -> T) -> Run<T> {
+    match catch_panic(job) {
+        Ok(result) => Run::Done(result),
+        Err(message) => Run::Panicked(message),
+    }
+}
+
+/// Runs `job` for every day, up to `options.parallel` days at the same time.
+///
+/// `done` is called with the outcome of each day in the order of `days`. Returning `false`
+/// from it stops the run; days that are still running are abandoned.
+pub fn run_days<T, J, D>(days: &[u8], options: RunOptions, job: J, mut done: D)
+where
+    T: Send + 'static,
+    J: Fn(u8) -> T + Send + Sync + 'static,
+    D: FnMut(u8, Run<T>) -> bool,
+{
+    let job = Arc::new(job);
+    let (sender, receiver) = mpsc::channel();
+
+    let mut pending = days.iter().copied();
+    let mut running: HashMap<u8, (Instant, JoinHandle<()>)> = HashMap::new();
+    let mut timed_out: Vec<JoinHandle<()>> = vec![];
+    let mut finished: BTreeMap<u8, Run<T>> = BTreeMap::new();
+    let mut next = 0;
+
+    while next < days.len() {
+        if options.wait_for_timed_out {
+            for handle in timed_out.drain(..) {
+                let _ = handle.join();
+            }
+        }
+
+        while running.len() < options.parallel.max(1) {
+            let Some(day) = pending.next() else {
+                break;
+            };
+            let job = Arc::clone(&job);
+            let sender = sender.clone();
+            let spawned = thread::Builder::new()
+                .name(format!("day{:02}", day))
+                .stack_size(STACK_SIZE)
+                .spawn(move || {
+                    let _ = sender.send((day, run_job(|| job(day))));
+                });
+            match spawned {
+                Ok(handle) => {
+                    running.insert(day, (Instant::now(), handle));
+                }
+                Err(e) => {
+                    finished.insert(day, Run::Panicked(e.to_string()));
+                }
+            }
+        }
+
+        let received = match options.timeout {
+            Some(timeout) => {
+                let first_deadline = running
+                    .values()
+                    .map(|(started, _)| *started + timeout)
+                    .min();
+                let wait = first_deadline.map_or(Duration::ZERO, |deadline| {
+                    deadline.saturating_duration_since(Instant::now())
+                });
+                receiver.recv_timeout(wait).ok()
+            }
+            None if running.is_empty() => None,
+            None => receiver.recv().ok(),
+        };
+
+        match received {
+            // results of abandoned days are dropped.
+            Some((day, result)) => {
+                if running.remove(&day).is_some() {
+                    finished.insert(day, result);
+                }
+            }
+            None => {
+                if let Some(timeout) = options.timeout {
+                    let now = Instant::now();
+                    let expired: Vec<u8> = running
+                        .iter()
+                        .filter(|(_, (started, _))| now.duration_since(*started) >= timeout)
+                        .map(|(&day, _)| day)
+                        .collect();
+                    for day in expired {
+                        if let Some((_, handle)) = running.remove(&day) {
+                            timed_out.push(handle);
+                        }
+                        finished.insert(day, Run::TimedOut(timeout));
+                    }
+                }
+            }
+        }
+
+        while let Some(result) = days.get(next).and_then(|day| finished.remove(day)) {
+            if !done(days[next], result) {
+                return;
+            }
+            next += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[test]
+    fn test_parse_day_selection() {
+        let days = |s: &str| s.parse::<DaySelection>().map(|s| s.days().to_vec());
+
+        assert_eq!(days("5"), Ok(vec![5]));
+        assert_eq!(days("3..7"), Ok(vec![3, 4, 5, 6]));
+        assert_eq!(days("3..=7"), Ok(vec![3, 4, 5, 6, 7]));
+        assert_eq!(days("23.."), Ok(vec![23, 24, 25]));
+        assert_eq!(days("..3"), Ok(vec![1, 2]));
+        assert_eq!(days("10,1..=2,2"), Ok(vec![1, 2, 10]));
+        assert!(days("0").is_err());
+        assert!(days("26").is_err());
+        assert!(days("7..3").is_err());
+        assert!(days("abc").is_err());
+    }
+
+    #[test]
+    fn test_excerpt() {
+        assert_eq!(excerpt("one line", 2), "one line");
+        assert_eq!(excerpt("a\nb\nc\nd", 2), "a\nb\n... (2 more lines)");
+    }
+
+    #[test]
+    fn test_run_days() {
+        capture_panics();
+        let options = RunOptions {
+            parallel: 3,
+            timeout: Some(Duration::from_millis(200)),
+            ..RunOptions::default()
+        };
+
+        let mut outcomes = vec![];
+        run_days(
+            &[1, 2, 3, 4],
+            options,
+            |day| match day {
+                2 => panic!("day {} broke", day),
+                3 => thread::sleep(Duration::from_secs(2)),
+                _ => thread::sleep(Duration::from_millis(10 * (5 - day as u64))),
+            },
+            |day, outcome| {
+                outcomes.push((day, outcome));
+                true
+            },
+        );
+
+        assert_eq!(outcomes.len(), 4);
+        assert_eq!(outcomes[0], (1, Run::Done(())));
+        assert!(
+            matches!(&outcomes[1], (2, Run::Panicked(message)) if message.starts_with("day 2 broke at src/runner.rs")),
+            "{:?}",
+            outcomes[1]
+        );
+        assert_eq!(outcomes[2], (3, Run::TimedOut(Duration::from_millis(200))));
+        assert_eq!(outcomes[3], (4, Run::Done(())));
+
+        // panics outside of jobs, e.g. of other tests, go to the previous hook.
+        let _ = panic::catch_unwind(|| panic!("not a job"));
+        assert_eq!(LAST_PANIC.with(|last| last.borrow_mut().take()), None);
+    }
+
+    #[test]
+    fn test_run_days_waits_for_timed_out() {
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            wait_for_timed_out: true,
+            ..RunOptions::default()
+        };
+        let slow_done = Arc::new(AtomicBool::new(false));
+
+        let mut outcomes = vec![];
+        run_days(
+            &[1, 2],
+            options,
+            move |day| {
+                if day == 1 {
+                    thread::sleep(Duration::from_millis(300));
+                    slow_done.store(true, Ordering::SeqCst);
+                }
+                slow_done.load(Ordering::SeqCst)
+            },
+            |day, outcome| {
+                outcomes.push((day, outcome));
+                true
+            },
+        );
+
+        assert_eq!(outcomes[0], (1, Run::TimedOut(Duration::from_millis(50))));
+        assert_eq!(outcomes[1], (2, Run::Done(true)));
+    }
+
+    #[test]
+    fn test_run_days_stops() {
+        let mut seen = vec![];
+        run_days(
+            &[1, 2, 3],
+            RunOptions::default(),
+            |day| day,
+            |day, _| {
+                seen.push(day);
+                day < 2
+            },
+        );
+        assert_eq!(seen, vec![1, 2]);
+    }
+}
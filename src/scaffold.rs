@@ -0,0 +1,274 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::project_root;
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+/// What scaffolding does to a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Create,
+    Overwrite,
+    /// Edits an existing file, e.g. to register the day.
+    Update,
+    /// Fetched from the website, only listed in dry runs since downloads happen before the plan.
+    Download,
+    Keep,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Create => write!(f, "create"),
+            Action::Overwrite => write!(f, "overwrite"),
+            Action::Update => write!(f, "update"),
+            Action::Download => write!(f, "download"),
+            Action::Keep => write!(f, "keep"),
+        }
+    }
+}
+
+/// A planned change to a single file, applied after the whole plan was printed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub path: PathBuf,
+    pub action: Action,
+    pub content: String,
+    /// Why a file is kept.
+    pub reason: Option<&'static str>,
+}
+
+impl Change {
+    fn keep(path: PathBuf, reason: &'static str) -> Self {
+        Change {
+            path,
+            action: Action::Keep,
+            content: String::new(),
+            reason: Some(reason),
+        }
+    }
+
+    /// Writes a generated file. Files with content of their own are only replaced with `force`.
+    pub fn file(path: PathBuf, content: String, force: bool) -> Self {
+        match fs::read_to_string(&path) {
+            Err(_) => Change {
+                path,
+                action: Action::Create,
+                content,
+                reason: None,
+            },
+            Ok(existing) if existing == content => Change::keep(path, "unchanged"),
+            Ok(existing) if force || existing.trim().is_empty() => Change {
+                path,
+                action: Action::Overwrite,
+                content,
+                reason: None,
+            },
+            Ok(_) => Change::keep(path, "exists, use `--force` to overwrite"),
+        }
+    }
+
+    /// Creates a file that holds user data, such as the input. Existing files are never replaced.
+    pub fn new_file(path: PathBuf, content: String) -> Self {
+        if path.exists() {
+            return Change::keep(path, "exists");
+        }
+        Change {
+            path,
+            action: Action::Create,
+            content,
+            reason: None,
+        }
+    }
+
+    /// Downloads a file. `replace` is false if the existing file would be kept.
+    pub fn download(path: PathBuf, replace: bool) -> Self {
+        if !replace {
+            return Change::keep(path, "exists");
+        }
+        Change {
+            path,
+            action: Action::Download,
+            content: String::new(),
+            reason: None,
+        }
+    }
+
+    /// Edits a source file. `edit` returns `None` if the file already has the change.
+    pub fn edit(path: PathBuf, edit: impl FnOnce(&str) -> Option<String>) -> io::Result<Self> {
+        let existing = fs::read_to_string(&path)?;
+        Ok(match edit(&existing) {
+            Some(content) => Change {
+                path,
+                action: Action::Update,
+                content,
+                reason: None,
+            },
+            None => Change::keep(path, "already registered"),
+        })
+    }
+
+    pub fn apply(&self) -> io::Result<()> {
+        // downloads are written by the client.
+        if matches!(self.action, Action::Keep | Action::Download) {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, &self.content)
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = self.path.strip_prefix(project_root()).unwrap_or(&self.path);
+        write!(f, "{:<9} {}", self.action.to_string(), path.display())?;
+        if let Some(reason) = self.reason {
+            write!(f, " ({})", reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Inserts `line` among the lines that `matches`, keeping them sorted. Returns `None` if no line matches.
+fn insert_sorted(source: &str, line: &str, matches: impl Fn(&str) -> bool) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let existing: Vec<usize> = (0..lines.len()).filter(|&i| matches(lines[i])).collect();
+    let last = *existing.last()?;
+    let index = existing
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .unwrap_or(last + 1);
+
+    let mut lines = lines;
+    lines.insert(index, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds `pub mod dayNN;` and its `Day::new` entry to a year module, e.g. `src/2022/mod.rs`.
+/// Returns `None` if the day is already registered.
+pub fn register_day(source: &str, day: u8) -> Option<String> {
+    let module = format!("pub mod day{:02};", day);
+    let entry = format!("        Day::new::<day{:02}::Day{:02}>(),", day, day);
+    if source.lines().any(|line| line.trim() == module) {
+        return None;
+    }
+
+    let source = insert_sorted(source, &module, |line| line.starts_with("pub mod day"))
+        .unwrap_or_else(|| {
+            source.replacen("pub fn all()", &format!("{}\n\npub fn all()", module), 1)
+        });
+
+    let source = insert_sorted(&source, &entry, |line| {
+        line.trim_start().starts_with("Day::new::<")
+    })
+    .unwrap_or_else(|| source.replacen("vec![]", &format!("vec![\n{}\n    ]", entry), 1));
+
+    Some(source)
+}
+
+/// Declares the module of a year in `src/lib.rs`. Returns `None` if it is already declared.
+pub fn register_year_module(source: &str, year: u16) -> Option<String> {
+    let module = format!("pub mod y{};", year);
+    if source.lines().any(|line| line.trim() == module) {
+        return None;
+    }
+
+    let lines: Vec<&str> = source.lines().collect();
+    let last = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod y") && line.ends_with(';'))?;
+
+    let mut lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    lines.insert(last + 1, format!("#[path = \"{}/mod.rs\"]", year));
+    lines.insert(last + 2, module);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the days of a year to the registry in `src/days.rs`. Returns `None` if they are already there.
+pub fn register_year_days(source: &str, year: u16) -> Option<String> {
+    let all = format!("crate::y{}::all()", year);
+    if source.contains(&all) {
+        return None;
+    }
+
+    let end = source.find("].concat()")?;
+    Some(format!("{}, {}{}", &source[..end], all, &source[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_MODULE: &str = "use crate::solution::Day;
+
+pub const YEAR: u16 = 2022;
+
+pub mod day01;
+pub mod day03;
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<day01::Day01>(),
+        Day::new::<day03::Day03>(),
+    ]
+}
+";
+
+    #[test]
+    fn test_download() {
+        let path = project_root().join("target/scaffold-download-test.txt");
+        let change = Change::download(path.clone(), true);
+        assert_eq!(change.action, Action::Download);
+        assert_eq!(
+            change.to_string(),
+            "download  target/scaffold-download-test.txt"
+        );
+        change.apply().unwrap();
+        assert!(!path.exists());
+
+        assert_eq!(Change::download(path, false).action, Action::Keep);
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registered = register_day(YEAR_MODULE, 2).unwrap();
+        assert!(registered.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(registered.contains(
+            "        Day::new::<day01::Day01>(),\n        Day::new::<day02::Day02>(),\n        Day::new::<day03::Day03>(),\n"
+        ));
+        assert_eq!(register_day(&registered, 2), None);
+
+        let registered = register_day(YEAR_MODULE, 4).unwrap();
+        assert!(registered.contains("pub mod day03;\npub mod day04;\n"));
+        assert!(registered.contains("Day::new::<day04::Day04>(),\n    ]"));
+    }
+
+    #[test]
+    fn test_register_day_in_new_year() {
+        let source = "pub const YEAR: u16 = 2023;\n\npub fn all() -> Vec<Day> {\n    vec![]\n}\n";
+        let registered = register_day(source, 1).unwrap();
+        assert_eq!(
+            registered,
+            "pub const YEAR: u16 = 2023;\n\npub mod day01;\n\npub fn all() -> Vec<Day> {\n    vec![\n        Day::new::<day01::Day01>(),\n    ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib =
+            "pub mod year;\n\n#[path = \"2022/mod.rs\"]\npub mod y2022;\n\npub const A: u8 = 1;\n";
+        let registered = register_year_module(lib, 2023).unwrap();
+        assert!(registered
+            .contains("pub mod y2022;\n#[path = \"2023/mod.rs\"]\npub mod y2023;\n\npub const A"));
+        assert_eq!(register_year_module(&registered, 2023), None);
+
+        let days = "fn registered() -> Vec<Day> {\n    [crate::y2022::all()].concat()\n}\n";
+        let registered = register_year_days(days, 2023).unwrap();
+        assert!(registered.contains("[crate::y2022::all(), crate::y2023::all()].concat()"));
+        assert_eq!(register_year_days(&registered, 2023), None);
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::error::PuzzleError;
+use std::fmt::Display;
+
+/// A solution for a single puzzle day.
+///
+/// `parse` turns the raw puzzle input into a value that is shared by both parts. Input it
+/// can not read is reported with a [`PuzzleError`] pointing at the offending line and column.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, PuzzleError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Option<Self::PartOne>;
+
+    fn part_two(input: &Self::Input<'_>) -> Option<Self::PartTwo>;
+}
+
+/// Parses the input and solves one part. Errors carry the day of the solution.
+pub type Solver = fn(&str) -> Result<Option<String>, PuzzleError>;
+
+/// Parses the input once, for solving both parts from it. Errors carry the day of the solution.
+pub type Parser = fn(&str) -> Result<Box<dyn Parsed + '_>, PuzzleError>;
+
+/// Input parsed by a [`Parser`].
+pub trait Parsed {
+    /// Answer to part `1` or `2`, without parsing the input again.
+    fn solve(&self, part: u8) -> Option<String>;
+}
+
+/// Type-erased handle to a [`Solution`], used by the day registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+    pub parse: Parser,
+}
+
+impl Day {
+    pub fn new<S: Solution + 'static>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            part_one: run_part_one::<S>,
+            part_two: run_part_two::<S>,
+            parse: parse_input::<S>,
+        }
+    }
+
+    /// Solver for part `1` or `2`.
+    pub fn solver(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part_one),
+            2 => Some(self.part_two),
+            _ => None,
+        }
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<S::Input<'_>, PuzzleError> {
+    S::parse(input).map_err(|e| e.with_day(S::DAY))
+}
+
+struct ParsedInput<'a, S: Solution>(S::Input<'a>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn solve(&self, part: u8) -> Option<String> {
+        match part {
+            1 => S::part_one(&self.0).map(|answer| answer.to_string()),
+            2 => S::part_two(&self.0).map(|answer| answer.to_string()),
+            _ => None,
+        }
+    }
+}
+
+fn parse_input<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed + '_>, PuzzleError> {
+    Ok(Box::new(ParsedInput::<S>(parse::<S>(input)?)))
+}
+
+fn run_part_one<S: Solution>(input: &str) -> Result<Option<String>, PuzzleError> {
+    Ok(S::part_one(&parse::<S>(input)?).map(|answer| answer.to_string()))
+}
+
+fn run_part_two<S: Solution>(input: &str) -> Result<Option<String>, PuzzleError> {
+    Ok(S::part_two(&parse::<S>(input)?).map(|answer| answer.to_string()))
+}
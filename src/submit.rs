@@ -0,0 +1,338 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::year::get_year_dir;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Response of the server to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part is locked or was already solved.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Whether the outcome says something about the answer and belongs into the history.
+    pub fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Wrong => write!(f, "That's not the right answer."),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Outcome::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "You gave an answer too recently, {} left to wait.", wait)
+            }
+            Outcome::RateLimited { wait: None } => write!(f, "You gave an answer too recently."),
+            Outcome::WrongLevel => write!(
+                f,
+                "You don't seem to be solving the right level. Is it locked or already solved?"
+            ),
+        }
+    }
+}
+
+/// Reads the outcome from the page returned after posting an answer.
+/// Returns `None` if the page contains none of the known messages.
+pub fn parse_outcome(html: &str) -> Option<Outcome> {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let tags = Regex::new(r"<[^>]+>").unwrap();
+
+    let content = article
+        .captures(html)
+        .and_then(|c| c.get(1))
+        .map_or(html, |m| m.as_str());
+    let text = tags.replace_all(content, "");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (.+?) left to wait").unwrap();
+        Outcome::RateLimited {
+            wait: wait.captures(&text).map(|c| c[1].to_string()),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        return None;
+    };
+
+    Some(outcome)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Guess {
+    pub fn new(year: u16, day: u8, part: u8, answer: &str, outcome: Outcome) -> Self {
+        Guess {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Why an answer was rejected without asking the server.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved(String),
+    AlreadyGuessed(Outcome),
+    TooHigh(String),
+    TooLow(String),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with \"{}\".", answer)
+            }
+            Rejection::AlreadyGuessed(outcome) => {
+                write!(f, "this answer was submitted before: {}", outcome)
+            }
+            Rejection::TooHigh(bound) => {
+                write!(f, "\"{}\" was already too high, try a lower answer.", bound)
+            }
+            Rejection::TooLow(bound) => {
+                write!(f, "\"{}\" was already too low, try a higher answer.", bound)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, serde_json::Error),
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::Io(path, e) => {
+                write!(f, "could not access guesses \"{}\": {}", path.display(), e)
+            }
+            HistoryError::Parse(path, e) => {
+                write!(f, "could not parse guesses \"{}\": {}", path.display(), e)
+            }
+        }
+    }
+}
+
+pub fn get_history_path(year: u16) -> PathBuf {
+    get_year_dir(year).join("answers").join("guesses.json")
+}
+
+/// Every answer submitted for a year, stored in `src/<year>/answers/guesses.json`.
+#[derive(Debug, Default)]
+pub struct History {
+    pub year: u16,
+    pub guesses: Vec<Guess>,
+}
+
+impl History {
+    /// Loads the history. A missing file is an empty history.
+    pub fn load(year: u16) -> Result<Self, HistoryError> {
+        let path = get_history_path(year);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Ok(History {
+                    year,
+                    guesses: vec![],
+                })
+            }
+            Err(e) => return Err(HistoryError::Io(path, e)),
+        };
+
+        serde_json::from_str(&content)
+            .map(|guesses| History { year, guesses })
+            .map_err(|e| HistoryError::Parse(path, e))
+    }
+
+    pub fn save(&self) -> Result<(), HistoryError> {
+        let path = get_history_path(self.year);
+        let json = serde_json::to_string_pretty(&self.guesses)
+            .map_err(|e| HistoryError::Parse(path.clone(), e))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| HistoryError::Io(path.clone(), e))?;
+        }
+        fs::write(&path, json + "\n").map_err(|e| HistoryError::Io(path, e))
+    }
+
+    pub fn record(&mut self, guess: Guess) {
+        self.guesses.push(guess);
+    }
+
+    /// Checks an answer against earlier guesses for the same part.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), Rejection> {
+        let guesses = || {
+            self.guesses
+                .iter()
+                .filter(move |g| g.year == year && g.day == day && g.part == part)
+        };
+
+        if let Some(correct) = guesses().find(|g| g.outcome == Outcome::Correct) {
+            return Err(Rejection::AlreadySolved(correct.answer.clone()));
+        }
+
+        if let Some(guess) = guesses().find(|g| g.answer == answer) {
+            return Err(Rejection::AlreadyGuessed(guess.outcome.clone()));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        for guess in guesses() {
+            let Ok(bound) = guess.answer.parse::<i128>() else {
+                continue;
+            };
+
+            match guess.outcome {
+                Outcome::TooHigh if value >= bound => {
+                    return Err(Rejection::TooHigh(guess.answer.clone()))
+                }
+                Outcome::TooLow if value <= bound => {
+                    return Err(Rejection::TooLow(guess.answer.clone()))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            parse_outcome(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Some(Outcome::Correct)
+        );
+        assert_eq!(
+            parse_outcome(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Some(Outcome::TooHigh)
+        );
+        assert_eq!(
+            parse_outcome(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(Outcome::TooLow)
+        );
+        assert_eq!(
+            parse_outcome(&page("That's not the right answer. If you're stuck, ...")),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(
+            parse_outcome(&page(
+                "You gave an answer too recently; you have to wait.  You have 4m 32s left to wait."
+            )),
+            Some(Outcome::RateLimited {
+                wait: Some("4m 32s".into())
+            })
+        );
+        assert_eq!(
+            parse_outcome(&page("You don't seem to be solving the right level.")),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(parse_outcome(&page("Something else")), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = History::default();
+        history.record(Guess::new(2022, 1, 1, "100", Outcome::TooLow));
+        history.record(Guess::new(2022, 1, 1, "500", Outcome::TooHigh));
+        history.record(Guess::new(2022, 1, 1, "abc", Outcome::Wrong));
+
+        assert_eq!(history.check(2022, 1, 1, "300"), Ok(()));
+        assert_eq!(
+            history.check(2022, 1, 1, "abc"),
+            Err(Rejection::AlreadyGuessed(Outcome::Wrong))
+        );
+        assert_eq!(
+            history.check(2022, 1, 1, "50"),
+            Err(Rejection::TooLow("100".into()))
+        );
+        assert_eq!(
+            history.check(2022, 1, 1, "500"),
+            Err(Rejection::AlreadyGuessed(Outcome::TooHigh))
+        );
+        assert_eq!(
+            history.check(2022, 1, 1, "501"),
+            Err(Rejection::TooHigh("500".into()))
+        );
+        assert_eq!(history.check(2022, 1, 2, "50"), Ok(()));
+        assert_eq!(history.check(2021, 1, 1, "50"), Ok(()));
+
+        history.record(Guess::new(2022, 1, 1, "300", Outcome::Correct));
+        assert_eq!(
+            history.check(2022, 1, 1, "301"),
+            Err(Rejection::AlreadySolved("300".into()))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config;
+use regex::Regex;
+use std::{error::Error, fmt::Display, fs, io, path::PathBuf};
+
+/// Templates shipped in `templates/`, used when the file was removed from the templates directory.
+const BUILTIN: [(&str, &str); 5] = [
+    ("day.rs", include_str!("../templates/day.rs")),
+    ("day-nom.rs", include_str!("../templates/day-nom.rs")),
+    ("examples.toml", include_str!("../templates/examples.toml")),
+    ("answers.toml", include_str!("../templates/answers.toml")),
+    ("year.rs", include_str!("../templates/year.rs")),
+];
+
+#[derive(Debug)]
+pub enum TemplateError {
+    Io(PathBuf, io::Error),
+    Missing(PathBuf),
+    /// The template contains a `{{PLACEHOLDER}}` that has no value.
+    Placeholder {
+        template: String,
+        placeholder: String,
+    },
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::Io(path, e) => {
+                write!(f, "could not read template \"{}\": {}", path.display(), e)
+            }
+            TemplateError::Missing(path) => {
+                write!(f, "template \"{}\" does not exist.", path.display())
+            }
+            TemplateError::Placeholder {
+                template,
+                placeholder,
+            } => write!(
+                f,
+                "template \"{}\" uses the unknown placeholder {{{{{}}}}}.",
+                template, placeholder
+            ),
+        }
+    }
+}
+
+impl Error for TemplateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TemplateError::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a template, e.g. `day.rs`, from the templates directory, falling back to the built-in copy.
+pub fn load(file_name: &str) -> Result<String, TemplateError> {
+    let path = config::get().templates_dir().join(file_name);
+    match fs::read_to_string(&path) {
+        Ok(content) => Ok(content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => BUILTIN
+            .iter()
+            .find(|(name, _)| *name == file_name)
+            .map(|(_, content)| content.to_string())
+            .ok_or(TemplateError::Missing(path)),
+        Err(e) => Err(TemplateError::Io(path, e)),
+    }
+}
+
+/// Replaces every `{{KEY}}` with its value. Fails on placeholders without a value.
+pub fn render(
+    file_name: &str,
+    template: &str,
+    values: &[(&str, &str)],
+) -> Result<String, TemplateError> {
+    let rendered = values
+        .iter()
+        .fold(template.to_string(), |rendered, (key, value)| {
+            rendered.replace(&format!("{{{{{}}}}}", key), value)
+        });
+
+    let placeholder = Regex::new(r"\{\{\s*([A-Za-z_]+)\s*\}\}").unwrap();
+    match placeholder.captures(&rendered) {
+        Some(c) => Err(TemplateError::Placeholder {
+            template: file_name.to_string(),
+            placeholder: c[1].to_string(),
+        }),
+        None => Ok(rendered),
+    }
+}
+
+/// Checks that an answer type passed to `--type` looks like a Rust type, e.g. `u64` or `String`.
+pub fn is_type_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | ':' | '<' | '>' | ' ' | ','))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: [(&str, &str); 6] = [
+        ("YEAR", "2022"),
+        ("DAY", "5"),
+        ("DAY_PADDED", "05"),
+        ("PART_ONE_TYPE", "String"),
+        ("PART_TWO_TYPE", "String"),
+        ("EXAMPLES_DIR", "src/2022/examples"),
+    ];
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("t", "Day{{DAY_PADDED}} -> {{PART_ONE_TYPE}}", &VALUES).unwrap(),
+            "Day05 -> String"
+        );
+        assert!(matches!(
+            render("t", "{{ NAME }}", &VALUES),
+            Err(TemplateError::Placeholder { placeholder, .. }) if placeholder == "NAME"
+        ));
+    }
+
+    #[test]
+    fn test_builtin_templates() {
+        for (name, template) in BUILTIN {
+            let rendered = render(name, template, &VALUES).unwrap();
+            assert!(!rendered.contains("{{"), "{}", name);
+        }
+
+        let day = render("day.rs", BUILTIN[0].1, &VALUES).unwrap();
+        assert!(day.contains("impl Solution for Day05"));
+        assert!(day.contains("fn part_one(&_input: &&str) -> Option<String>"));
+    }
+
+    #[test]
+    fn test_is_type_name() {
+        assert!(is_type_name("u32"));
+        assert!(is_type_name("String"));
+        assert!(is_type_name("Vec<usize>"));
+        assert!(!is_type_name(""));
+        assert!(!is_type_name("u32; }"));
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::project_root;
+use crate::{config, days};
+use std::{
+    env,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Year used by all commands when `--year` is not passed.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// `AOC_YEAR` if set, else `year` from `aoc.toml`, else the newest year with registered
+/// solutions, else the latest event.
+pub fn default_year() -> u16 {
+    env::var(YEAR_ENV)
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .or(config::get().year)
+        .or_else(|| days::years().last().copied())
+        .unwrap_or_else(latest_event_year)
+}
+
+/// Directory holding the solutions, inputs, examples, puzzles and answers of a year, e.g. `src/2022`.
+pub fn get_year_dir(year: u16) -> PathBuf {
+    project_root().join("src").join(year.to_string())
+}
+
+/// Path of a per-day file of a year, e.g. `src/2022/inputs/05.txt`, see [`config::Paths`].
+pub fn get_day_path(year: u16, folder: &str, day: u8, extension: &str) -> PathBuf {
+    config::get()
+        .dir(folder, year)
+        .join(format!("{:02}.{}", day, extension))
+}
+
+/// Puzzles unlock in December, so before that the latest event is the one of last year.
+pub fn latest_event_year() -> u16 {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+
+    // Converts days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    if month == 12 {
+        year as u16
+    } else {
+        year as u16 - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_day_path() {
+        assert!(get_day_path(2022, "inputs", 5, "txt").ends_with("src/2022/inputs/05.txt"));
+        assert!(get_day_path(2021, "puzzles", 12, "md").ends_with("src/2021/puzzles/12.md"));
+    }
+}
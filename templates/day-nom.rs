@@ -0,0 +1,50 @@
+use crate::error::PuzzleError;
+use crate::helpers::parse::{lines, parse_all, unsigned};
+use crate::solution::Solution;
+use nom::{
+    character::complete::{alpha1, space1},
+    sequence::separated_pair,
+    IResult,
+};
+
+#[derive(Debug)]
+pub struct Line<'a> {
+    pub name: &'a str,
+    pub value: u32,
+}
+
+// abc 123
+fn line(input: &str) -> IResult<&str, Line<'_>> {
+    let (input, (name, value)) = separated_pair(alpha1, space1, unsigned)(input)?;
+    Ok((input, Line { name, value }))
+}
+
+pub struct Day{{DAY_PADDED}};
+
+impl Solution for Day{{DAY_PADDED}} {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = {{DAY}};
+
+    type Input<'a> = Vec<Line<'a>>;
+    type PartOne = {{PART_ONE_TYPE}};
+    type PartTwo = {{PART_TWO_TYPE}};
+
+    fn parse(input: &str) -> Result<Vec<Line<'_>>, PuzzleError> {
+        parse_all(lines(line), input)
+    }
+
+    fn part_one(_lines: &Vec<Line>) -> Option<{{PART_ONE_TYPE}}> {
+        None
+    }
+
+    fn part_two(_lines: &Vec<Line>) -> Option<{{PART_TWO_TYPE}}> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day{{DAY_PADDED}});
+}
@@ -0,0 +1,32 @@
+use crate::error::PuzzleError;
+use crate::solution::Solution;
+
+pub struct Day{{DAY_PADDED}};
+
+impl Solution for Day{{DAY_PADDED}} {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = {{DAY}};
+
+    type Input<'a> = &'a str;
+    type PartOne = {{PART_ONE_TYPE}};
+    type PartTwo = {{PART_TWO_TYPE}};
+
+    fn parse(input: &str) -> Result<&str, PuzzleError> {
+        Ok(input)
+    }
+
+    fn part_one(&_input: &&str) -> Option<{{PART_ONE_TYPE}}> {
+        None
+    }
+
+    fn part_two(&_input: &&str) -> Option<{{PART_TWO_TYPE}}> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day{{DAY_PADDED}});
+}
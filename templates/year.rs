@@ -0,0 +1,11 @@
+/*
+ * Solutions for {{YEAR}}.
+ * Add the module and its `Day::new::<DayNN>()` entry here after scaffolding a new day.
+ */
+use crate::solution::Day;
+
+pub const YEAR: u16 = {{YEAR}};
+
+pub fn all() -> Vec<Day> {
+    vec![]
+}
@@ -0,0 +1,27 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process::Command;
+
+/// Days 10 and 11 print while solving, which must not end up in a report on stdout.
+#[test]
+fn test_json_report_on_stdout() {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+        .args(["--year", "2022", "1..=13", "--example", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let parts = report["parts"].as_array().unwrap();
+    assert_eq!(parts.len(), 26);
+    assert_eq!(parts[18]["day"], 10);
+    assert_eq!(parts[18]["status"], "solved");
+    assert_eq!(parts[20]["day"], 11);
+    assert_eq!(parts[20]["answer"], "10605");
+}